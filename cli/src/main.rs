@@ -1,9 +1,7 @@
 use {
-    anchor_lang::{InstructionData, ToAccountMetas},
+    anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas},
     anchor_spl::{
-        associated_token::{
-            self, get_associated_token_address, get_associated_token_address_with_program_id,
-        },
+        associated_token::{self, get_associated_token_address_with_program_id},
         token::{
             spl_token::{
                 self,
@@ -22,6 +20,7 @@ use {
                 BaseStateWithExtensions, ExtensionType, StateWithExtensions,
             },
             instruction::reallocate,
+            offchain::AccountFetchError,
             proof::ProofLocation,
             solana_zk_token_sdk::{
                 encryption::{
@@ -37,18 +36,34 @@ use {
     },
     clap::{Parser, Subcommand},
     ctw_token::{
-        accounts::{Initialize, Unwrap, Wrap},
-        derive_authority, derive_confidential_mint,
+        accounts::{
+            AcceptAdmin, AdoptLegacyWrapper, ApproveAccount, CloseWrapper,
+            FreezeConfidentialAccount, Initialize, InitializeGlobal, MigrateAuthority, SetPaused,
+            SetUnwrapLimit, SweepSurplus, SyncFreezeAuthority, Unwrap, UpdateConfidentialMint,
+            UpdateGlobalConfig, UpdateWrapperConfig, VerifyBacking, WithdrawFees, Wrap,
+        },
+        calculate_fee, derive_authority, derive_confidential_mint, derive_fee_vault,
+        derive_global_config, derive_legacy_token_vault, derive_program_data, derive_token_vault,
+        derive_wrapper_authority, derive_wrapper_config, merge_account_metas,
+        resolve_transfer_hook_accounts, CtwError, CtwEvent, InitializeOptions, WrapperConfig,
+    },
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        client_error::ClientError,
+        rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig},
+        rpc_filter::{Memcmp, RpcFilterType},
     },
-    solana_client::rpc_client::RpcClient,
     solana_sdk::{
-        instruction::Instruction,
+        commitment_config::CommitmentConfig,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        program_error::ProgramError,
         pubkey::{ParsePubkeyError, Pubkey},
         signature::{read_keypair_file, Keypair, Signature},
         signer::Signer,
         system_instruction::create_account,
         system_program,
-        transaction::Transaction,
+        transaction::{Transaction, TransactionError},
     },
     spl_associated_token_account::instruction::create_associated_token_account,
     std::{num::NonZeroI8, path::PathBuf, str::FromStr},
@@ -58,9 +73,17 @@ use {
 #[derive(Debug, Error)]
 enum Error {
     #[error(transparent)]
-    Client(#[from] solana_client::client_error::ClientError),
+    Anchor(#[from] anchor_lang::error::Error),
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("{0}")]
+    CtwToken(CtwError),
     #[error("Loading keypair. {:?}", self)]
     LoadingKeypair(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+    #[error("Resolving transfer hook accounts. {:?}", self)]
+    TransferHook(Box<dyn std::error::Error + Send + Sync>),
 }
 
 #[derive(Parser, Debug)]
@@ -72,6 +95,10 @@ struct Args {
     #[arg(short, long)]
     keypair_path: PathBuf,
 
+    /// The variant of the Confidential Wrapped Token among the wrappers of its SPL Token Mint.
+    #[arg(long, global = true, default_value_t = 0)]
+    variant: u16,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -83,6 +110,22 @@ enum Commands {
         /// The SPL Token Mint for which to create a Confidential Wrapped Token.
         #[arg(short, long)]
         token_mint: String,
+        /// Require new confidential token accounts to be approved.
+        #[arg(long)]
+        require_approval: bool,
+        /// The confidential transfer authority, defaults to the program authority.
+        #[arg(long)]
+        confidential_transfer_authority: Option<String>,
+        /// Do not set an auditor.
+        #[arg(long)]
+        no_auditor: bool,
+        /// The admin of the wrapper, defaults to the default wrapper admin, the mint authority or the program admin.
+        #[arg(long)]
+        admin: Option<String>,
+        /// The keypair of the SPL Token Mint's mint authority or of the program admin, co-signing the auditor,
+        /// the confidential transfer authority, the admin and requiring approval, defaults to the signer.
+        #[arg(long)]
+        auditor_authority_keypair_path: Option<PathBuf>,
     },
     /// Wrap a given token amount into the corresponding Confidential Wrapped Token.
     Wrap {
@@ -92,6 +135,10 @@ enum Commands {
         /// The amount to wrap.
         #[arg(short, long)]
         amount: u64,
+        /// The owner to wrap for, whose associated token account is credited in its public balance,
+        /// defaults to the signer, whose wrapped amount is deposited into its confidential balance.
+        #[arg(long)]
+        recipient: Option<String>,
     },
     /// Unwrap a given token amount of Confidential Wrapped Token into the corresponding SPL Token.
     Unwrap {
@@ -102,6 +149,218 @@ enum Commands {
         #[arg(short, long)]
         amount: u64,
     },
+    /// Update the confidential transfer extension of a Confidential Wrapped Token Mint.
+    UpdateConfidentialMint {
+        /// The SPL Token Mint of the Confidential Wrapped Token to update.
+        #[arg(short, long)]
+        token_mint: String,
+        /// Require new confidential token accounts to be approved.
+        #[arg(long)]
+        require_approval: bool,
+        /// Remove the auditor.
+        #[arg(long)]
+        no_auditor: bool,
+    },
+    /// Rotate the auditor of a Confidential Wrapped Token Mint.
+    RotateAuditor {
+        /// The SPL Token Mint of the Confidential Wrapped Token to update.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The keypair from which to derive the new auditor ElGamal public key.
+        #[arg(long, required_unless_present = "auditor_elgamal_pubkey")]
+        auditor_keypair_path: Option<PathBuf>,
+        /// The new base64 encoded auditor ElGamal public key.
+        #[arg(long, conflicts_with = "auditor_keypair_path")]
+        auditor_elgamal_pubkey: Option<String>,
+    },
+    /// Approve the confidential token account of an owner for a Confidential Wrapped Token requiring approval.
+    Approve {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The owner of the confidential token account to approve.
+        #[arg(short, long)]
+        owner: String,
+    },
+    /// Verify that the supply of a Confidential Wrapped Token is fully backed by its vault.
+    VerifyBacking {
+        /// The SPL Token Mint of the Confidential Wrapped Token to verify.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Mirror the freeze authority of an SPL Token Mint onto its Confidential Wrapped Token Mint.
+    SyncFreezeAuthority {
+        /// The SPL Token Mint of the Confidential Wrapped Token to sync.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Freeze the confidential token account of an owner for a Confidential Wrapped Token.
+    Freeze {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The owner of the confidential token account to freeze.
+        #[arg(short, long)]
+        owner: String,
+        /// The keypair of the SPL Token Mint's freeze authority, defaults to the signer.
+        #[arg(long)]
+        freeze_authority_keypair_path: Option<PathBuf>,
+    },
+    /// Thaw the confidential token account of an owner for a Confidential Wrapped Token.
+    Thaw {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The owner of the confidential token account to thaw.
+        #[arg(short, long)]
+        owner: String,
+        /// The keypair of the SPL Token Mint's freeze authority, defaults to the signer.
+        #[arg(long)]
+        freeze_authority_keypair_path: Option<PathBuf>,
+    },
+    /// Initialize the global state of the program, the signer must be the program's upgrade authority.
+    InitializeGlobal {
+        /// The admin of the program, defaults to the signer.
+        #[arg(long)]
+        admin: Option<String>,
+        /// The guardian of the program, defaults to the signer.
+        #[arg(long)]
+        guardian: Option<String>,
+    },
+    /// Pause or unpause wrapping and unwrapping for a Confidential Wrapped Token, or for every one of them.
+    SetPaused {
+        /// The SPL Token Mint of the Confidential Wrapped Token, every wrapper is affected if none is given.
+        #[arg(short, long)]
+        token_mint: Option<String>,
+        /// Whether wrapping and unwrapping should be paused.
+        #[arg(long, action = clap::ArgAction::Set)]
+        paused: bool,
+    },
+    /// Propose a new admin of the program, which must then accept it.
+    TransferAdmin {
+        /// The proposed admin of the program.
+        #[arg(long)]
+        new_admin: String,
+    },
+    /// Accept the admin role of the program, the signer must be the proposed admin.
+    AcceptAdmin,
+    /// Replace the guardian of the program.
+    SetGuardian {
+        /// The new guardian of the program.
+        #[arg(long)]
+        guardian: String,
+    },
+    /// Update the policy applied to new Confidential Wrapped Tokens.
+    SetDefaultPolicy {
+        /// The admin of new wrappers, the mint authority or else the program admin if none is given.
+        #[arg(long)]
+        default_wrapper_admin: Option<String>,
+        /// Whether new wrappers start paused.
+        #[arg(long)]
+        default_paused: bool,
+    },
+    /// Set the fees, in basis points, deducted when wrapping and unwrapping a Confidential Wrapped Token.
+    SetFees {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The fee deducted from the amount being wrapped.
+        #[arg(long)]
+        wrap_fee_bps: u16,
+        /// The fee deducted from the amount being unwrapped.
+        #[arg(long)]
+        unwrap_fee_bps: u16,
+    },
+    /// Set the maximum supply and the per-transaction wrap limits of a Confidential Wrapped Token.
+    SetLimits {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The maximum supply of the Confidential Wrapped Token, unlimited if none is given.
+        #[arg(long)]
+        max_supply: Option<u64>,
+        /// The maximum amount wrapped in a single transaction, unlimited if none is given.
+        #[arg(long)]
+        max_wrap_per_tx: Option<u64>,
+        /// The minimum amount wrapped in a single transaction.
+        #[arg(long, default_value_t = 0)]
+        min_wrap_amount: u64,
+    },
+    /// Set the maximum amount of a Confidential Wrapped Token unwrapped within any window of slots.
+    SetUnwrapLimit {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The maximum amount unwrapped within the window, unlimited if none is given.
+        #[arg(long)]
+        limit: Option<u64>,
+        /// The length of the window in slots.
+        #[arg(long, default_value_t = 0)]
+        window_slots: u64,
+    },
+    /// Show the unwrap limit of a Confidential Wrapped Token and its remaining capacity.
+    UnwrapLimit {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Show the fees of a Confidential Wrapped Token and the balance of its fee vault.
+    Fees {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Collect the fees of a Confidential Wrapped Token from its fee vault.
+    CollectFees {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The amount to collect, defaults to the balance of the fee vault.
+        #[arg(short, long)]
+        amount: Option<u64>,
+        /// The token account receiving the fees, defaults to the signer's associated token account.
+        #[arg(long)]
+        destination: Option<String>,
+    },
+    /// Set the token account receiving the surplus of the vault of a Confidential Wrapped Token.
+    SetTreasury {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The treasury token account, unset if none is given.
+        #[arg(long)]
+        treasury: Option<String>,
+    },
+    /// Show the amount held by the vault of a Confidential Wrapped Token in excess of its supply.
+    Surplus {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Sweep the surplus of the vault of a Confidential Wrapped Token into its treasury.
+    SweepSurplus {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Close a Confidential Wrapped Token once its supply is zero and its vaults are empty.
+    CloseWrapper {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+        /// The account receiving the rent of the closed accounts, defaults to the signer.
+        #[arg(long)]
+        destination: Option<String>,
+    },
+    /// Move a Confidential Wrapped Token created under the shared program authority to its own wrapper authority.
+    MigrateAuthority {
+        /// The SPL Token Mint of the Confidential Wrapped Token.
+        #[arg(short, long)]
+        token_mint: String,
+    },
+    /// Find every Confidential Wrapped Token still under the shared program authority, adopting those created
+    /// before the Wrapper Config existed, and move each of them to its own wrapper authority.
+    Migrate,
 }
 
 fn parse_pubkey(value: &str) -> Result<Pubkey, ParsePubkeyError> {
@@ -115,10 +374,206 @@ fn load_keypair(path: PathBuf) -> Result<Keypair, Error> {
     }
 }
 
+/// Derives the auditor ElGamal public key from the given signer.
+fn derive_auditor_pubkey(signer: &Keypair) -> [u8; 32] {
+    ElGamalKeypair::new_from_signer(signer, "auditor".as_ref())
+        .unwrap()
+        .pubkey()
+        .to_bytes()
+}
+
+/// Parses a base64 encoded ElGamal public key.
+fn parse_elgamal_pubkey(value: &str) -> Option<[u8; 32]> {
+    anchor_lang::__private::base64::decode(value)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+}
+
+/// Decodes the Confidential Wrapped Token Program error a transaction failed with, if any.
+fn map_client_error(e: ClientError) -> Error {
+    if let Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) =
+        e.get_transaction_error()
+    {
+        if let Ok(ctw_error) = CtwError::try_from(code) {
+            return Error::CtwToken(ctw_error);
+        }
+    }
+    Error::Client(e)
+}
+
+/// Fetches the program which owns the given mint, either the SPL Token Program or the Token Extensions Program.
+fn get_token_program(rpc_client: &RpcClient, token_mint: &Pubkey) -> Result<Pubkey, Error> {
+    match rpc_client.get_account(token_mint) {
+        Ok(a) => Ok(a.owner),
+        Err(e) => Err(Error::Client(e)),
+    }
+}
+
+/// Resolves the extra accounts required by the transfer hook of the given mint, if it has one.
+fn get_transfer_hook_accounts(
+    rpc_client: &RpcClient,
+    token_program: &Pubkey,
+    source: &Pubkey,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Result<Vec<AccountMeta>, Error> {
+    // An account which does not exist resolves to no data, but failing to fetch one fails the resolution
+    let fetch_account_data_fn = |address| {
+        let data = rpc_client
+            .get_account_with_commitment(&address, rpc_client.commitment())
+            .map(|response| response.value.map(|a| a.data))
+            .map_err(|e| Box::new(e) as AccountFetchError);
+        async move { data }
+    };
+
+    match futures::executor::block_on(resolve_transfer_hook_accounts(
+        fetch_account_data_fn,
+        token_program,
+        source,
+        token_mint,
+        destination,
+        authority,
+        amount,
+    )) {
+        Ok(accounts) => Ok(accounts),
+        Err(e) => Err(Error::TransferHook(e)),
+    }
+}
+
+/// Fetches the state of the given variant of the Confidential Wrapped Token of the given mint.
+fn get_wrapper_config(
+    rpc_client: &RpcClient,
+    token_mint: &Pubkey,
+    variant: u16,
+) -> Result<WrapperConfig, Error> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let data = match rpc_client.get_account_data(&wrapper_config) {
+        Ok(d) => d,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+
+    Ok(WrapperConfig::try_deserialize(&mut data.as_slice())?)
+}
+
+/// Fetches the balance of the given token account.
+fn get_token_account_amount(rpc_client: &RpcClient, token_account: &Pubkey) -> Result<u64, Error> {
+    let data = match rpc_client.get_account_data(token_account) {
+        Ok(d) => d,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+
+    Ok(StateWithExtensions::<Account>::unpack(&data)?.base.amount)
+}
+
+/// Fetches the state of the given variant of the Confidential Wrapped Token of the given mint and the amount
+/// held by its vault in excess of its supply.
+fn get_surplus(
+    rpc_client: &RpcClient,
+    token_mint: &Pubkey,
+    variant: u16,
+) -> Result<(WrapperConfig, u64), Error> {
+    let wrapper_config = get_wrapper_config(rpc_client, token_mint, variant)?;
+    let vault_amount = get_token_account_amount(rpc_client, &wrapper_config.token_vault)?;
+    let data = match rpc_client.get_account_data(&wrapper_config.confidential_mint) {
+        Ok(d) => d,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let supply = StateWithExtensions::<Mint>::unpack(&data)?.base.supply;
+
+    Ok((wrapper_config, vault_amount.saturating_sub(supply)))
+}
+
+/// Finds the SPL Token Mints of the wrappers whose Confidential Wrapped Token Mint is still minted by the
+/// shared program authority, from the vaults the latter owns.
+fn find_legacy_wrappers(rpc_client: &RpcClient) -> Result<Vec<Pubkey>, Error> {
+    let (program_authority, _) = derive_authority();
+    let mut token_mints = vec![];
+
+    for token_program in [spl_token::ID, token_2022::ID] {
+        // The owner of a token account follows its mint
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                32,
+                program_authority.as_ref(),
+            ))]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        let accounts = match rpc_client.get_program_accounts_with_config(&token_program, config) {
+            Ok(a) => a,
+            Err(e) => {
+                return Err(Error::Client(e));
+            }
+        };
+
+        for (address, account) in accounts {
+            let token_mint = match StateWithExtensions::<Account>::unpack(&account.data) {
+                Ok(token_account) => token_account.base.mint,
+                Err(_) => continue,
+            };
+            // Fee vaults are owned by the program authority too until their wrapper is migrated
+            if address != derive_legacy_token_vault(&token_mint, &token_program) {
+                continue;
+            }
+
+            let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+            let data = match rpc_client.get_account_data(&confidential_mint) {
+                Ok(d) => d,
+                Err(_) => continue,
+            };
+            let mint_authority = StateWithExtensions::<Mint>::unpack(&data)?
+                .base
+                .mint_authority;
+            if Option::<Pubkey>::from(mint_authority) == Some(program_authority) {
+                token_mints.push(token_mint);
+            }
+        }
+    }
+
+    Ok(token_mints)
+}
+
+/// Prints the events emitted by the program in the given transaction.
+fn print_events(rpc_client: &RpcClient, signature: &Signature) {
+    let config = RpcTransactionConfig {
+        encoding: None,
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+
+    let logs = match rpc_client.get_transaction_with_config(signature, config) {
+        Ok(tx) => tx
+            .transaction
+            .meta
+            .and_then(|meta| Option::<Vec<String>>::from(meta.log_messages))
+            .unwrap_or_default(),
+        Err(e) => {
+            println!("Could not fetch transaction logs.\nError: {:?}", e);
+            return;
+        }
+    };
+
+    for event in CtwEvent::from_logs(&logs) {
+        println!("Event: {:?}", event);
+    }
+}
+
 fn main() {
     let cli = Args::parse();
 
     let rpc_client = RpcClient::new(cli.rpc_client);
+    let variant = cli.variant;
 
     let signer = match load_keypair(cli.keypair_path) {
         Ok(k) => k,
@@ -129,7 +584,14 @@ fn main() {
     };
 
     match cli.command {
-        Commands::Initialize { token_mint } => {
+        Commands::Initialize {
+            token_mint,
+            require_approval,
+            confidential_transfer_authority,
+            no_auditor,
+            admin,
+            auditor_authority_keypair_path,
+        } => {
             println!("Initializing Confidential Wrapped Token Mint..");
 
             let token_mint = match parse_pubkey(&token_mint) {
@@ -141,21 +603,86 @@ fn main() {
             };
 
             println!("SPL Token Mint: {}", token_mint);
+            println!("Variant: {}", variant);
+
+            let confidential_transfer_authority = match confidential_transfer_authority
+                .as_deref()
+                .map(parse_pubkey)
+                .transpose()
+            {
+                Ok(p) => p,
+                Err(e) => {
+                    println!(
+                        "Failed to parse confidential transfer authority pubkey.\nError: {:?}",
+                        e
+                    );
+                    return;
+                }
+            };
+
+            let admin = match admin.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse admin pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let auditor_authority = match auditor_authority_keypair_path {
+                Some(path) => match load_keypair(path) {
+                    Ok(k) => Some(k),
+                    Err(e) => {
+                        println!(
+                            "Could not load the given auditor authority keypair.\nError: {:?}",
+                            e
+                        );
+                        return;
+                    }
+                },
+                None => None,
+            };
+
+            let options = InitializeOptions {
+                auto_approve_new_accounts: !require_approval,
+                confidential_transfer_authority,
+                auditor_elgamal_pubkey: (!no_auditor).then(|| derive_auditor_pubkey(&signer)),
+                variant,
+                admin,
+            };
 
-            match initialize(&rpc_client, &signer, &token_mint) {
+            // Only the mint authority or the program admin may choose who can decrypt the transfers
+            // and who administers or may hold the wrapper
+            let auditor_authority = (options.auditor_elgamal_pubkey.is_some()
+                || options.confidential_transfer_authority.is_some()
+                || options.admin.is_some()
+                || !options.auto_approve_new_accounts)
+                .then(|| auditor_authority.as_ref().unwrap_or(&signer));
+
+            match initialize(
+                &rpc_client,
+                &signer,
+                auditor_authority,
+                &token_mint,
+                options,
+            ) {
                 Ok(s) => {
                     println!("Successfully initialized confidential wrapped token..\nTransaction signature: https://solana.fm/tx/{}", s);
+                    print_events(&rpc_client, &s);
                 }
                 Err(e) => {
                     println!(
-                        "Failed to initialize confidential wrapped token.\nError: {:?}",
+                        "Failed to initialize confidential wrapped token.\nError: {}",
                         e
                     );
                     return;
                 }
             };
         }
-        Commands::Wrap { token_mint, amount } => {
+        Commands::Wrap {
+            token_mint,
+            amount,
+            recipient,
+        } => {
             println!(
                 "Wrapping {} of {} into the equivalent Confidential Wrapped Token Mint..",
                 amount, token_mint
@@ -169,10 +696,65 @@ fn main() {
                 }
             };
 
+            let recipient = match recipient.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse recipient pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
             println!("SPL Token Mint: {}", token_mint);
 
-            match create_and_configure_confidential_token_account(&rpc_client, &signer, &token_mint)
-            {
+            // The recipient deposits and applies the wrapped amount itself
+            if let Some(recipient) = recipient {
+                println!("Recipient: {}", recipient);
+
+                match wrap(
+                    &rpc_client,
+                    &signer,
+                    &token_mint,
+                    variant,
+                    Some(&recipient),
+                    amount,
+                ) {
+                    Ok(s) => {
+                        println!(
+                            "Successfully wrapped into the public balance of the recipient...\nTransaction signature: https://solana.fm/tx/{}",
+                            s
+                        );
+                        print_events(&rpc_client, &s);
+                    }
+                    Err(e) => {
+                        println!("Failed to wrap.\nError: {}", e);
+                    }
+                };
+                return;
+            }
+
+            let wrapper_config = match get_wrapper_config(&rpc_client, &token_mint, variant) {
+                Ok(c) => c,
+                Err(e) => {
+                    println!("Failed to fetch wrapper config.\nError: {}", e);
+                    return;
+                }
+            };
+
+            // Only the amount left after the wrap fee is deposited into the confidential balance
+            let wrapped_amount = match calculate_fee(amount, wrapper_config.wrap_fee_bps) {
+                Ok(fee) => amount - fee,
+                Err(e) => {
+                    println!("Failed to calculate wrap fee.\nError: {}", e);
+                    return;
+                }
+            };
+
+            match create_and_configure_confidential_token_account(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+            ) {
                 Ok(s) => {
                     println!("Successfully created and configured token account for confidential token usage..\nTransaction signature: https://solana.fm/tx/{}", s);
                 }
@@ -182,20 +764,21 @@ fn main() {
                 }
             };
 
-            match wrap(&rpc_client, &signer, &token_mint, amount) {
+            match wrap(&rpc_client, &signer, &token_mint, variant, None, amount) {
                 Ok(s) => {
                     println!(
                         "Successfully wrapped...\nTransaction signature: https://solana.fm/tx/{}",
                         s
                     );
+                    print_events(&rpc_client, &s);
                 }
                 Err(e) => {
-                    println!("Failed to wrap.\nError: {:?}", e);
+                    println!("Failed to wrap.\nError: {}", e);
                     return;
                 }
             };
 
-            match post_wrap(&rpc_client, &signer, &token_mint, amount) {
+            match post_wrap(&rpc_client, &signer, &token_mint, variant, wrapped_amount) {
                 Ok(s) => {
                     println!(
                         "Wrapped amount is now available for confidential transfers!\nTransaction signature: https://solana.fm/tx/{}",
@@ -227,7 +810,7 @@ fn main() {
 
             println!("SPL Token Mint: {}", token_mint);
 
-            match withdraw_and_verify(&rpc_client, &signer, &token_mint, amount) {
+            match withdraw_and_verify(&rpc_client, &signer, &token_mint, variant, amount) {
                 Ok(s) => {
                     println!(
                         "Successfully processed withdrawawl from confidential balance..\nTransaction signature: https://solana.fm/tx/{}",
@@ -240,289 +823,2391 @@ fn main() {
                 }
             };
 
-            match unwrap(&rpc_client, &signer, &token_mint, amount) {
+            match unwrap(&rpc_client, &signer, &token_mint, variant, amount) {
                 Ok(s) => {
                     println!(
                         "Successfully unwrapped!\nTransaction signature: https://solana.fm/tx/{}",
                         s
                     );
+                    print_events(&rpc_client, &s);
                 }
                 Err(e) => {
-                    println!("Failed to unwrap.\nError: {:?}", e);
+                    println!("Failed to unwrap.\nError: {}", e);
                     return;
                 }
             };
         }
-    }
-}
-
-fn initialize(
-    rpc_client: &RpcClient,
-    signer: &Keypair,
-    token_mint: &Pubkey,
-) -> Result<Signature, Error> {
-    let elgamal_keypair = ElGamalKeypair::new_from_signer(signer, "auditor".as_ref()).unwrap();
+        Commands::UpdateConfidentialMint {
+            token_mint,
+            require_approval,
+            no_auditor,
+        } => {
+            println!("Updating Confidential Wrapped Token Mint..");
 
-    let (program_authority, _) = derive_authority();
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
 
-    println!("Confidnetial Wrapped Token Mint: {}", confidential_mint);
+            println!("SPL Token Mint: {}", token_mint);
 
-    let token_vault = get_associated_token_address(&program_authority, token_mint);
+            let auditor_elgamal_pubkey = (!no_auditor).then(|| derive_auditor_pubkey(&signer));
 
-    let ix = Instruction {
-        accounts: Initialize {
-            token_mint: *token_mint,
-            program_authority,
-            confidential_mint,
-            token_vault,
-            payer: signer.pubkey(),
-            token_program: spl_token::ID,
-            associated_token_program: associated_token::ID,
-            token_extensions_program: token_2022::ID,
-            system_program: system_program::ID,
-        }
-        .to_account_metas(None),
-        program_id: ctw_token::ID,
-        data: ctw_token::instruction::Initialize {
-            auditor_pubkey: elgamal_keypair.pubkey().to_bytes(),
-        }
-        .data(),
-    };
-    let latest_blockhash = match rpc_client.get_latest_blockhash() {
-        Ok(lb) => lb,
-        Err(e) => {
-            return Err(Error::Client(e));
+            match update_confidential_mint(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                !require_approval,
+                auditor_elgamal_pubkey,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully updated confidential wrapped token..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to update confidential wrapped token.\nError: {}", e);
+                    return;
+                }
+            };
         }
-    };
-    let tx = Transaction::new_signed_with_payer(
-        &vec![ix],
-        Some(&signer.pubkey()),
-        &[signer],
-        latest_blockhash,
-    );
+        Commands::RotateAuditor {
+            token_mint,
+            auditor_keypair_path,
+            auditor_elgamal_pubkey,
+        } => {
+            println!("Rotating auditor of Confidential Wrapped Token Mint..");
 
-    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
-        Ok(s) => Ok(s),
-        Err(e) => Err(Error::Client(e)),
-    }
-}
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
 
-fn wrap(
-    rpc_client: &RpcClient,
-    signer: &Keypair,
-    token_mint: &Pubkey,
-    amount: u64,
-) -> Result<Signature, Error> {
-    let (program_authority, _) = derive_authority();
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
-    let token_vault = get_associated_token_address(&program_authority, token_mint);
-    let confidential_token_account = get_associated_token_address_with_program_id(
-        &signer.pubkey(),
-        &confidential_mint,
-        &token_2022::ID,
-    );
+            println!("SPL Token Mint: {}", token_mint);
 
-    let (token_account, ixs) = if token_mint == &native_mint::id() {
-        let keypair = Keypair::new();
-        let token_account = keypair.pubkey();
-        let lamports = rpc_client
-            .get_minimum_balance_for_rent_exemption(TokenAccount::LEN)
-            .unwrap();
-        (
-            Some(keypair),
-            vec![
-                create_account(
-                    &signer.pubkey(),
-                    &token_account,
-                    lamports + amount,
-                    TokenAccount::LEN as u64,
-                    &spl_token::id(),
-                ),
-                initialize_account(
-                    &spl_token::id(),
-                    &token_account,
-                    token_mint,
-                    &signer.pubkey(),
-                )
-                .unwrap(),
-                Instruction {
-                    accounts: Wrap {
-                        token_mint: *token_mint,
-                        token_account,
-                        program_authority: program_authority,
-                        confidential_mint,
-                        confidential_token_account,
-                        token_vault,
-                        authority: signer.pubkey(),
-                        payer: signer.pubkey(),
-                        token_program: spl_token::ID,
-                        token_extensions_program: token_2022::ID,
+            let auditor_elgamal_pubkey = match (auditor_keypair_path, auditor_elgamal_pubkey) {
+                (Some(path), _) => match load_keypair(path) {
+                    Ok(k) => derive_auditor_pubkey(&k),
+                    Err(e) => {
+                        println!("Could not load the given auditor keypair.\nError: {:?}", e);
+                        return;
                     }
-                    .to_account_metas(None),
-                    program_id: ctw_token::ID,
-                    data: ctw_token::instruction::Wrap { amount }.data(),
                 },
-                close_account(
-                    &spl_token::id(),
-                    &token_account,
-                    &signer.pubkey(),
-                    &signer.pubkey(),
-                    &[],
-                )
-                .unwrap(),
-            ],
-        )
-    } else {
-        let token_account = get_associated_token_address(&signer.pubkey(), token_mint);
-        (
-            None,
-            vec![Instruction {
-                accounts: Wrap {
-                    token_mint: *token_mint,
-                    token_account,
-                    program_authority: program_authority,
-                    confidential_mint,
-                    confidential_token_account,
-                    token_vault,
-                    authority: signer.pubkey(),
-                    payer: signer.pubkey(),
-                    token_program: spl_token::ID,
-                    token_extensions_program: token_2022::ID,
+                (None, Some(pubkey)) => match parse_elgamal_pubkey(&pubkey) {
+                    Some(p) => p,
+                    None => {
+                        println!("Failed to parse auditor ElGamal pubkey.");
+                        return;
+                    }
+                },
+                (None, None) => unreachable!(),
+            };
+
+            match rotate_auditor(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                auditor_elgamal_pubkey,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully rotated auditor..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
                 }
-                .to_account_metas(None),
-                program_id: ctw_token::ID,
-                data: ctw_token::instruction::Wrap { amount }.data(),
-            }],
-        )
-    };
+                Err(e) => {
+                    println!("Failed to rotate auditor.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::Approve { token_mint, owner } => {
+            println!("Approving confidential token account..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let owner = match parse_pubkey(&owner) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse owner pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!("Owner: {}", owner);
+
+            match approve_account(&rpc_client, &signer, &token_mint, variant, &owner) {
+                Ok(s) => {
+                    println!(
+                        "Successfully approved confidential token account..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!(
+                        "Failed to approve confidential token account.\nError: {}",
+                        e
+                    );
+                    return;
+                }
+            };
+        }
+        Commands::VerifyBacking { token_mint } => {
+            println!("Verifying backing of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            match verify_backing(&rpc_client, &signer, &token_mint, variant) {
+                Ok(s) => {
+                    println!(
+                        "Confidential Wrapped Token is fully backed!\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                }
+                Err(e) => {
+                    println!("Failed to verify backing.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SyncFreezeAuthority { token_mint } => {
+            println!("Syncing freeze authority of Confidential Wrapped Token Mint..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            match sync_freeze_authority(&rpc_client, &signer, &token_mint, variant) {
+                Ok(s) => {
+                    println!(
+                        "Successfully synced freeze authority..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to sync freeze authority.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::Freeze {
+            token_mint,
+            owner,
+            freeze_authority_keypair_path,
+        } => {
+            println!("Freezing confidential token account..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let owner = match parse_pubkey(&owner) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse owner pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let freeze_authority = match freeze_authority_keypair_path {
+                Some(path) => match load_keypair(path) {
+                    Ok(k) => Some(k),
+                    Err(e) => {
+                        println!(
+                            "Could not load the given freeze authority keypair.\nError: {:?}",
+                            e
+                        );
+                        return;
+                    }
+                },
+                None => None,
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!("Owner: {}", owner);
+
+            match set_account_frozen(
+                &rpc_client,
+                &signer,
+                freeze_authority.as_ref().unwrap_or(&signer),
+                &token_mint,
+                variant,
+                &owner,
+                true,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully froze confidential token account..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to freeze confidential token account.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::Thaw {
+            token_mint,
+            owner,
+            freeze_authority_keypair_path,
+        } => {
+            println!("Thawing confidential token account..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let owner = match parse_pubkey(&owner) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse owner pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let freeze_authority = match freeze_authority_keypair_path {
+                Some(path) => match load_keypair(path) {
+                    Ok(k) => Some(k),
+                    Err(e) => {
+                        println!(
+                            "Could not load the given freeze authority keypair.\nError: {:?}",
+                            e
+                        );
+                        return;
+                    }
+                },
+                None => None,
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!("Owner: {}", owner);
+
+            match set_account_frozen(
+                &rpc_client,
+                &signer,
+                freeze_authority.as_ref().unwrap_or(&signer),
+                &token_mint,
+                variant,
+                &owner,
+                false,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully thawed confidential token account..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to thaw confidential token account.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::InitializeGlobal { admin, guardian } => {
+            println!("Initializing global state..");
+
+            let admin = match admin.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p.unwrap_or(signer.pubkey()),
+                Err(e) => {
+                    println!("Failed to parse admin pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let guardian = match guardian.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p.unwrap_or(signer.pubkey()),
+                Err(e) => {
+                    println!("Failed to parse guardian pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("Admin: {}", admin);
+            println!("Guardian: {}", guardian);
+
+            match initialize_global(&rpc_client, &signer, &admin, &guardian) {
+                Ok(s) => {
+                    println!(
+                        "Successfully initialized global state..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to initialize global state.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SetPaused { token_mint, paused } => {
+            if paused {
+                println!("Pausing wrapping and unwrapping..");
+            } else {
+                println!("Unpausing wrapping and unwrapping..");
+            }
+
+            let token_mint = match token_mint.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            match token_mint {
+                Some(token_mint) => println!("SPL Token Mint: {}", token_mint),
+                None => println!("SPL Token Mint: all"),
+            }
+
+            match set_paused(&rpc_client, &signer, token_mint.as_ref(), variant, paused) {
+                Ok(s) => {
+                    println!(
+                        "Successfully updated pause..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to update pause.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::TransferAdmin { new_admin } => {
+            println!("Proposing new admin of the program..");
+
+            let new_admin = match parse_pubkey(&new_admin) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse new admin pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("New admin: {}", new_admin);
+
+            match transfer_admin(&rpc_client, &signer, &new_admin) {
+                Ok(s) => {
+                    println!(
+                        "Successfully proposed new admin..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to propose new admin.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::AcceptAdmin => {
+            println!("Accepting admin of the program..");
+
+            match accept_admin(&rpc_client, &signer) {
+                Ok(s) => {
+                    println!(
+                        "Successfully accepted admin..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to accept admin.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SetGuardian { guardian } => {
+            println!("Replacing guardian of the program..");
+
+            let guardian = match parse_pubkey(&guardian) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse guardian pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("Guardian: {}", guardian);
+
+            match set_guardian(&rpc_client, &signer, &guardian) {
+                Ok(s) => {
+                    println!(
+                        "Successfully replaced guardian..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to replace guardian.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SetDefaultPolicy {
+            default_wrapper_admin,
+            default_paused,
+        } => {
+            println!("Updating default policy of new wrappers..");
+
+            let default_wrapper_admin = match default_wrapper_admin
+                .as_deref()
+                .map(parse_pubkey)
+                .transpose()
+            {
+                Ok(p) => p,
+                Err(e) => {
+                    println!(
+                        "Failed to parse default wrapper admin pubkey.\nError: {:?}",
+                        e
+                    );
+                    return;
+                }
+            };
+
+            match set_default_policy(&rpc_client, &signer, default_wrapper_admin, default_paused) {
+                Ok(s) => {
+                    println!(
+                        "Successfully updated default policy..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to update default policy.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SetFees {
+            token_mint,
+            wrap_fee_bps,
+            unwrap_fee_bps,
+        } => {
+            println!("Setting fees of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!(
+                "Wrap fee: {} bps, unwrap fee: {} bps",
+                wrap_fee_bps, unwrap_fee_bps
+            );
+
+            match set_fees(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                wrap_fee_bps,
+                unwrap_fee_bps,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully set fees..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to set fees.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SetLimits {
+            token_mint,
+            max_supply,
+            max_wrap_per_tx,
+            min_wrap_amount,
+        } => {
+            println!("Setting limits of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            match set_limits(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                max_supply,
+                max_wrap_per_tx,
+                min_wrap_amount,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully set limits..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to set limits.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::Fees { token_mint } => {
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            let wrapper_config = match get_wrapper_config(&rpc_client, &token_mint, variant) {
+                Ok(c) => c,
+                Err(e) => {
+                    println!("Failed to fetch wrapper config.\nError: {}", e);
+                    return;
+                }
+            };
+
+            let fee_vault_amount =
+                match get_token_account_amount(&rpc_client, &wrapper_config.fee_vault) {
+                    Ok(a) => a,
+                    Err(e) => {
+                        println!("Failed to fetch fee vault.\nError: {}", e);
+                        return;
+                    }
+                };
+
+            println!("Wrap fee: {} bps", wrapper_config.wrap_fee_bps);
+            println!("Unwrap fee: {} bps", wrapper_config.unwrap_fee_bps);
+            println!("Total fees collected: {}", wrapper_config.total_fees);
+            println!("Fee vault: {}", wrapper_config.fee_vault);
+            println!("Fee vault balance: {}", fee_vault_amount);
+        }
+        Commands::CollectFees {
+            token_mint,
+            amount,
+            destination,
+        } => {
+            println!("Collecting fees of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let destination = match destination.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse destination pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            let amount = match amount {
+                Some(a) => a,
+                None => {
+                    let (fee_vault, _) = derive_fee_vault(&token_mint, variant);
+                    match get_token_account_amount(&rpc_client, &fee_vault) {
+                        Ok(a) => a,
+                        Err(e) => {
+                            println!("Failed to fetch fee vault.\nError: {}", e);
+                            return;
+                        }
+                    }
+                }
+            };
+
+            println!("Amount: {}", amount);
+
+            match withdraw_fees(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                destination,
+                amount,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully collected fees..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to collect fees.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::SetUnwrapLimit {
+            token_mint,
+            limit,
+            window_slots,
+        } => {
+            println!("Setting unwrap limit of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            match set_unwrap_limit(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                limit,
+                window_slots,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully set unwrap limit..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to set unwrap limit.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::UnwrapLimit { token_mint } => {
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            let wrapper_config = match get_wrapper_config(&rpc_client, &token_mint, variant) {
+                Ok(c) => c,
+                Err(e) => {
+                    println!("Failed to fetch wrapper config.\nError: {}", e);
+                    return;
+                }
+            };
+
+            let unwrap_limit = match wrapper_config.unwrap_limit {
+                Some(l) => l,
+                None => {
+                    println!("Unwrap limit: none");
+                    return;
+                }
+            };
+
+            let slot = match rpc_client.get_slot() {
+                Ok(s) => s,
+                Err(e) => {
+                    println!("Failed to fetch slot.\nError: {}", e);
+                    return;
+                }
+            };
+
+            println!(
+                "Unwrap limit: {} per {} slots",
+                unwrap_limit, wrapper_config.unwrap_window_slots
+            );
+            let unwrap_outflow = wrapper_config.unwrap_outflow_at(slot);
+            println!("Unwrapped in window: {}", unwrap_outflow);
+            println!(
+                "Remaining capacity: {}",
+                unwrap_limit.saturating_sub(unwrap_outflow)
+            );
+        }
+        Commands::SetTreasury {
+            token_mint,
+            treasury,
+        } => {
+            println!("Setting treasury of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let treasury = match treasury.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse treasury pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!("Treasury: {:?}", treasury);
+
+            match set_treasury(&rpc_client, &signer, &token_mint, variant, treasury) {
+                Ok(s) => {
+                    println!(
+                        "Successfully set treasury..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to set treasury.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::Surplus { token_mint } => {
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            let (wrapper_config, surplus) = match get_surplus(&rpc_client, &token_mint, variant) {
+                Ok(s) => s,
+                Err(e) => {
+                    println!("Failed to fetch surplus.\nError: {}", e);
+                    return;
+                }
+            };
+
+            println!("Vault: {}", wrapper_config.token_vault);
+            println!("Surplus: {}", surplus);
+            match wrapper_config.treasury {
+                Some(treasury) => println!("Treasury: {}", treasury),
+                None => println!("Treasury: none"),
+            }
+        }
+        Commands::SweepSurplus { token_mint } => {
+            println!("Sweeping surplus of Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+
+            let (wrapper_config, surplus) = match get_surplus(&rpc_client, &token_mint, variant) {
+                Ok(s) => s,
+                Err(e) => {
+                    println!("Failed to fetch surplus.\nError: {}", e);
+                    return;
+                }
+            };
+
+            let treasury = match wrapper_config.treasury {
+                Some(t) => t,
+                None => {
+                    println!("Failed to sweep surplus.\nError: No treasury is set for the wrapper");
+                    return;
+                }
+            };
+
+            println!("Surplus: {}", surplus);
+            println!("Treasury: {}", treasury);
+
+            match sweep_surplus(
+                &rpc_client,
+                &signer,
+                &token_mint,
+                variant,
+                &treasury,
+                surplus,
+            ) {
+                Ok(s) => {
+                    println!(
+                        "Successfully swept surplus..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to sweep surplus.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::CloseWrapper {
+            token_mint,
+            destination,
+        } => {
+            println!("Closing Confidential Wrapped Token..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            let destination = match destination.as_deref().map(parse_pubkey).transpose() {
+                Ok(p) => p.unwrap_or(signer.pubkey()),
+                Err(e) => {
+                    println!("Failed to parse destination pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!("Destination: {}", destination);
+
+            match close_wrapper(&rpc_client, &signer, &token_mint, variant, &destination) {
+                Ok(s) => {
+                    println!(
+                        "Successfully closed wrapper..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to close wrapper.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::MigrateAuthority { token_mint } => {
+            println!("Migrating Confidential Wrapped Token authority..");
+
+            let token_mint = match parse_pubkey(&token_mint) {
+                Ok(p) => p,
+                Err(e) => {
+                    println!("Failed to parse token mint pubkey.\nError: {:?}", e);
+                    return;
+                }
+            };
+
+            println!("SPL Token Mint: {}", token_mint);
+            println!(
+                "Wrapper Authority: {}",
+                derive_wrapper_authority(&token_mint, 0).0
+            );
+
+            match migrate_authority(&rpc_client, &signer, &token_mint) {
+                Ok(s) => {
+                    println!(
+                        "Successfully migrated authority..\nTransaction signature: https://solana.fm/tx/{}",
+                        s
+                    );
+                    print_events(&rpc_client, &s);
+                }
+                Err(e) => {
+                    println!("Failed to migrate authority.\nError: {}", e);
+                    return;
+                }
+            };
+        }
+        Commands::Migrate => {
+            println!("Finding legacy Confidential Wrapped Tokens..");
+
+            let token_mints = match find_legacy_wrappers(&rpc_client) {
+                Ok(t) => t,
+                Err(e) => {
+                    println!("Failed to find legacy wrappers.\nError: {}", e);
+                    return;
+                }
+            };
+
+            println!("Legacy wrappers: {}", token_mints.len());
+
+            // A failing wrapper, such as one whose transfer fee its vault cannot cover, does not stop the others
+            for token_mint in token_mints {
+                println!("SPL Token Mint: {}", token_mint);
+
+                let (wrapper_config, _) = derive_wrapper_config(&token_mint, 0);
+                let has_wrapper_config = match rpc_client
+                    .get_account_with_commitment(&wrapper_config, rpc_client.commitment())
+                {
+                    Ok(response) => response.value.is_some(),
+                    Err(e) => {
+                        println!("Failed to fetch wrapper config.\nError: {}", e);
+                        continue;
+                    }
+                };
+
+                if !has_wrapper_config {
+                    match adopt_legacy_wrapper(&rpc_client, &signer, &token_mint) {
+                        Ok(s) => {
+                            println!(
+                                "Successfully adopted legacy wrapper..\nTransaction signature: https://solana.fm/tx/{}",
+                                s
+                            );
+                            print_events(&rpc_client, &s);
+                        }
+                        Err(e) => {
+                            println!("Failed to adopt legacy wrapper.\nError: {}", e);
+                            continue;
+                        }
+                    };
+                }
+
+                match migrate_authority(&rpc_client, &signer, &token_mint) {
+                    Ok(s) => {
+                        println!(
+                            "Successfully migrated authority..\nTransaction signature: https://solana.fm/tx/{}",
+                            s
+                        );
+                        print_events(&rpc_client, &s);
+                    }
+                    Err(e) => {
+                        println!("Failed to migrate authority.\nError: {}", e);
+                    }
+                };
+            }
+        }
+    }
+}
+
+fn initialize(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    auditor_authority: Option<&Keypair>,
+    token_mint: &Pubkey,
+    options: InitializeOptions,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, options.variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, options.variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, options.variant);
+    let (global_config, _) = derive_global_config();
+
+    println!("Confidnetial Wrapped Token Mint: {}", confidential_mint);
+
+    let (fee_vault, _) = derive_fee_vault(token_mint, options.variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_token_vault(token_mint, options.variant, &token_program);
+
+    let ix = Instruction {
+        accounts: Initialize {
+            token_mint: *token_mint,
+            wrapper_authority,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            token_vault,
+            fee_vault,
+            payer: signer.pubkey(),
+            auditor_authority: auditor_authority.map(|keypair| keypair.pubkey()),
+            token_program,
+            associated_token_program: associated_token::ID,
+            token_extensions_program: token_2022::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::Initialize { options }.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let mut signers = vec![signer];
+    signers.extend(auditor_authority);
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &signers,
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+/// Wraps into the signer's confidential token account, or into the public balance of the recipient's
+/// associated token account through `wrap_to`, creating the latter if it does not exist yet.
+fn wrap(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    recipient: Option<&Pubkey>,
+    amount: u64,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
+    let owner = recipient.copied().unwrap_or(signer.pubkey());
+    let confidential_token_account =
+        get_associated_token_address_with_program_id(&owner, &confidential_mint, &token_2022::ID);
+    let data = match recipient {
+        Some(_) => ctw_token::instruction::WrapTo { amount }.data(),
+        None => ctw_token::instruction::Wrap { amount }.data(),
+    };
+
+    let mut ixs = vec![];
+    if recipient.is_some() && rpc_client.get_account(&confidential_token_account).is_err() {
+        ixs.push(create_associated_token_account(
+            &signer.pubkey(),
+            &owner,
+            &confidential_mint,
+            &token_2022::ID,
+        ));
+    }
+
+    let (token_account, wrap_ixs) = if token_mint == &native_mint::id() {
+        let keypair = Keypair::new();
+        let token_account = keypair.pubkey();
+        let lamports = rpc_client
+            .get_minimum_balance_for_rent_exemption(TokenAccount::LEN)
+            .unwrap();
+        (
+            Some(keypair),
+            vec![
+                create_account(
+                    &signer.pubkey(),
+                    &token_account,
+                    lamports + amount,
+                    TokenAccount::LEN as u64,
+                    &spl_token::id(),
+                ),
+                initialize_account(
+                    &spl_token::id(),
+                    &token_account,
+                    token_mint,
+                    &signer.pubkey(),
+                )
+                .unwrap(),
+                Instruction {
+                    accounts: Wrap {
+                        token_mint: *token_mint,
+                        token_account,
+                        wrapper_authority: wrapper_authority,
+                        confidential_mint,
+                        wrapper_config,
+                        global_config,
+                        fee_vault,
+                        confidential_token_account,
+                        token_vault,
+                        authority: signer.pubkey(),
+                        payer: signer.pubkey(),
+                        token_program,
+                        token_extensions_program: token_2022::ID,
+                    }
+                    .to_account_metas(None),
+                    program_id: ctw_token::ID,
+                    data,
+                },
+                close_account(
+                    &spl_token::id(),
+                    &token_account,
+                    &signer.pubkey(),
+                    &signer.pubkey(),
+                    &[],
+                )
+                .unwrap(),
+            ],
+        )
+    } else {
+        let token_account = get_associated_token_address_with_program_id(
+            &signer.pubkey(),
+            token_mint,
+            &token_program,
+        );
+        let transfer_accounts = get_transfer_hook_accounts(
+            rpc_client,
+            &token_program,
+            &token_account,
+            token_mint,
+            &token_vault,
+            &signer.pubkey(),
+            amount,
+        )?;
+        let fee_transfer_accounts = get_transfer_hook_accounts(
+            rpc_client,
+            &token_program,
+            &token_vault,
+            token_mint,
+            &fee_vault,
+            &wrapper_authority,
+            amount,
+        )?;
+        let transfer_hook_accounts =
+            merge_account_metas([transfer_accounts, fee_transfer_accounts]);
+        (
+            None,
+            vec![Instruction {
+                accounts: Wrap {
+                    token_mint: *token_mint,
+                    token_account,
+                    wrapper_authority: wrapper_authority,
+                    confidential_mint,
+                    wrapper_config,
+                    global_config,
+                    fee_vault,
+                    confidential_token_account,
+                    token_vault,
+                    authority: signer.pubkey(),
+                    payer: signer.pubkey(),
+                    token_program,
+                    token_extensions_program: token_2022::ID,
+                }
+                .to_account_metas(None)
+                .into_iter()
+                .chain(transfer_hook_accounts)
+                .collect(),
+                program_id: ctw_token::ID,
+                data,
+            }],
+        )
+    };
+
+    ixs.extend(wrap_ixs);
+
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&signer.pubkey()));
+    tx.partial_sign(&[signer], latest_blockhash);
+
+    if let Some(signer) = token_account {
+        tx.partial_sign(&[&signer], latest_blockhash);
+    }
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn unwrap(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    amount: u64,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
+    let confidential_token_account = get_associated_token_address_with_program_id(
+        &signer.pubkey(),
+        &confidential_mint,
+        &token_2022::ID,
+    );
+
+    let (token_account, ixs) = if token_mint == &native_mint::id() {
+        let keypair = Keypair::new();
+        let token_account = keypair.pubkey();
+        let lamports = rpc_client
+            .get_minimum_balance_for_rent_exemption(TokenAccount::LEN)
+            .unwrap();
+        (
+            Some(keypair),
+            vec![
+                create_account(
+                    &signer.pubkey(),
+                    &token_account,
+                    lamports,
+                    TokenAccount::LEN as u64,
+                    &spl_token::id(),
+                ),
+                initialize_account(
+                    &spl_token::id(),
+                    &token_account,
+                    token_mint,
+                    &signer.pubkey(),
+                )
+                .unwrap(),
+                Instruction {
+                    accounts: Unwrap {
+                        token_mint: *token_mint,
+                        token_account,
+                        wrapper_authority: wrapper_authority,
+                        confidential_mint,
+                        wrapper_config,
+                        global_config,
+                        fee_vault,
+                        confidential_token_account,
+                        token_vault,
+                        authority: signer.pubkey(),
+                        payer: signer.pubkey(),
+                        token_program,
+                        token_extensions_program: token_2022::ID,
+                    }
+                    .to_account_metas(None),
+                    program_id: ctw_token::ID,
+                    data: ctw_token::instruction::Unwrap { amount }.data(),
+                },
+                close_account(
+                    &spl_token::id(),
+                    &token_account,
+                    &signer.pubkey(),
+                    &signer.pubkey(),
+                    &[],
+                )
+                .unwrap(),
+            ],
+        )
+    } else {
+        let token_account = get_associated_token_address_with_program_id(
+            &signer.pubkey(),
+            token_mint,
+            &token_program,
+        );
+        let transfer_accounts = get_transfer_hook_accounts(
+            rpc_client,
+            &token_program,
+            &token_vault,
+            token_mint,
+            &token_account,
+            &wrapper_authority,
+            amount,
+        )?;
+        let fee_transfer_accounts = get_transfer_hook_accounts(
+            rpc_client,
+            &token_program,
+            &token_vault,
+            token_mint,
+            &fee_vault,
+            &wrapper_authority,
+            amount,
+        )?;
+        let transfer_hook_accounts =
+            merge_account_metas([transfer_accounts, fee_transfer_accounts]);
+        (
+            None,
+            vec![Instruction {
+                accounts: Unwrap {
+                    token_mint: *token_mint,
+                    token_account,
+                    wrapper_authority: wrapper_authority,
+                    confidential_mint,
+                    wrapper_config,
+                    global_config,
+                    fee_vault,
+                    confidential_token_account,
+                    token_vault,
+                    authority: signer.pubkey(),
+                    payer: signer.pubkey(),
+                    token_program,
+                    token_extensions_program: token_2022::ID,
+                }
+                .to_account_metas(None)
+                .into_iter()
+                .chain(transfer_hook_accounts)
+                .collect(),
+                program_id: ctw_token::ID,
+                data: ctw_token::instruction::Unwrap { amount }.data(),
+            }],
+        )
+    };
+
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+
+    let mut tx = Transaction::new_with_payer(&ixs, Some(&signer.pubkey()));
+    tx.partial_sign(&[signer], latest_blockhash);
+
+    if let Some(signer) = token_account {
+        tx.partial_sign(&[&signer], latest_blockhash);
+    }
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn update_confidential_mint(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateConfidentialMint {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::UpdateConfidentialMint {
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn rotate_auditor(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    auditor_elgamal_pubkey: [u8; 32],
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateConfidentialMint {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::RotateAuditor {
+            auditor_elgamal_pubkey,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn approve_account(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    owner: &Pubkey,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let confidential_token_account =
+        get_associated_token_address_with_program_id(owner, &confidential_mint, &token_2022::ID);
+
+    let ix = Instruction {
+        accounts: ApproveAccount {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            confidential_token_account,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::ApproveAccount {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn verify_backing(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+) -> Result<Signature, Error> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
+
+    let ix = Instruction {
+        accounts: VerifyBacking {
+            token_mint: *token_mint,
+            token_vault,
+            confidential_mint,
+            wrapper_config,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::VerifyBacking {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn sync_freeze_authority(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+
+    let ix = Instruction {
+        accounts: SyncFreezeAuthority {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            wrapper_authority,
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SyncFreezeAuthority {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+/// Freezes or thaws the confidential token account of the given owner with the SPL Token Mint's freeze authority.
+fn set_account_frozen(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    freeze_authority: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    owner: &Pubkey,
+    freeze: bool,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let confidential_token_account =
+        get_associated_token_address_with_program_id(owner, &confidential_mint, &token_2022::ID);
+
+    let ix = Instruction {
+        accounts: FreezeConfidentialAccount {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            confidential_token_account,
+            wrapper_authority,
+            freeze_authority: freeze_authority.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: if freeze {
+            ctw_token::instruction::FreezeAccount {}.data()
+        } else {
+            ctw_token::instruction::ThawAccount {}.data()
+        },
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, freeze_authority],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn initialize_global(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    admin: &Pubkey,
+    guardian: &Pubkey,
+) -> Result<Signature, Error> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: InitializeGlobal {
+            global_config,
+            program: ctw_token::ID,
+            program_data: derive_program_data(),
+            upgrade_authority: signer.pubkey(),
+            payer: signer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::InitializeGlobal {
+            admin: *admin,
+            guardian: *guardian,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_paused(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: Option<&Pubkey>,
+    variant: u16,
+    paused: bool,
+) -> Result<Signature, Error> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: SetPaused {
+            global_config,
+            wrapper_config: token_mint
+                .map(|token_mint| derive_wrapper_config(token_mint, variant).0),
+            authority: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetPaused { paused }.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn transfer_admin(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    new_admin: &Pubkey,
+) -> Result<Signature, Error> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateGlobalConfig {
+            global_config,
+            admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::TransferAdmin {
+            new_admin: *new_admin,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn accept_admin(rpc_client: &RpcClient, signer: &Keypair) -> Result<Signature, Error> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: AcceptAdmin {
+            global_config,
+            pending_admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::AcceptAdmin {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_guardian(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    guardian: &Pubkey,
+) -> Result<Signature, Error> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateGlobalConfig {
+            global_config,
+            admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetGuardian {
+            guardian: *guardian,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_default_policy(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    default_wrapper_admin: Option<Pubkey>,
+    default_paused: bool,
+) -> Result<Signature, Error> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateGlobalConfig {
+            global_config,
+            admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetDefaultPolicy {
+            default_wrapper_admin,
+            default_paused,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_fees(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    wrap_fee_bps: u16,
+    unwrap_fee_bps: u16,
+) -> Result<Signature, Error> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateWrapperConfig {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetFees {
+            wrap_fee_bps,
+            unwrap_fee_bps,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_limits(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    max_supply: Option<u64>,
+    max_wrap_per_tx: Option<u64>,
+    min_wrap_amount: u64,
+) -> Result<Signature, Error> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateWrapperConfig {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetLimits {
+            max_supply,
+            max_wrap_per_tx,
+            min_wrap_amount,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_treasury(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    treasury: Option<Pubkey>,
+) -> Result<Signature, Error> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateWrapperConfig {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            admin: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetTreasury { treasury }.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
 
+fn sweep_surplus(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    treasury: &Pubkey,
+    amount: u64,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
+
+    let transfer_hook_accounts = get_transfer_hook_accounts(
+        rpc_client,
+        &token_program,
+        &token_vault,
+        token_mint,
+        treasury,
+        &wrapper_authority,
+        amount,
+    )?;
+
+    let ix = Instruction {
+        accounts: SweepSurplus {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            token_vault,
+            treasury: *treasury,
+            wrapper_authority,
+            token_program,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SweepSurplus {}.data(),
+    };
     let latest_blockhash = match rpc_client.get_latest_blockhash() {
         Ok(lb) => lb,
         Err(e) => {
             return Err(Error::Client(e));
         }
     };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
 
-    let mut tx = Transaction::new_with_payer(&ixs, Some(&signer.pubkey()));
-    tx.partial_sign(&[signer], latest_blockhash);
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
 
-    if let Some(signer) = token_account {
-        tx.partial_sign(&[&signer], latest_blockhash);
+fn close_wrapper(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    destination: &Pubkey,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
+
+    let ix = Instruction {
+        accounts: CloseWrapper {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            token_vault,
+            fee_vault,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            destination: *destination,
+            token_program,
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::CloseWrapper {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
     }
+}
+
+fn adopt_legacy_wrapper(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<Signature, Error> {
+    let (program_authority, _) = derive_authority();
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let token_vault = derive_legacy_token_vault(token_mint, &token_program);
+
+    let ix = Instruction {
+        accounts: AdoptLegacyWrapper {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            token_vault,
+            fee_vault,
+            program_authority,
+            payer: signer.pubkey(),
+            token_program,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::AdoptLegacyWrapper {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
 
     match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
         Ok(s) => Ok(s),
-        Err(e) => Err(Error::Client(e)),
+        Err(e) => Err(map_client_error(e)),
     }
 }
 
-fn unwrap(
+fn migrate_authority(
     rpc_client: &RpcClient,
     signer: &Keypair,
     token_mint: &Pubkey,
-    amount: u64,
 ) -> Result<Signature, Error> {
     let (program_authority, _) = derive_authority();
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
-    let token_vault = get_associated_token_address(&program_authority, token_mint);
-    let confidential_token_account = get_associated_token_address_with_program_id(
-        &signer.pubkey(),
-        &confidential_mint,
-        &token_2022::ID,
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+    let WrapperConfig {
+        token_vault: legacy_token_vault,
+        admin,
+        ..
+    } = get_wrapper_config(rpc_client, token_mint, 0)?;
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
+
+    let amount = get_token_account_amount(rpc_client, &legacy_token_vault)?;
+    let transfer_hook_accounts = get_transfer_hook_accounts(
+        rpc_client,
+        &token_program,
+        &legacy_token_vault,
+        token_mint,
+        &token_vault,
+        &program_authority,
+        amount,
+    )?;
+
+    let ix = Instruction {
+        accounts: MigrateAuthority {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            legacy_token_vault,
+            token_vault,
+            fee_vault,
+            program_authority,
+            wrapper_authority,
+            admin,
+            payer: signer.pubkey(),
+            token_program,
+            associated_token_program: associated_token::ID,
+            token_extensions_program: token_2022::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::MigrateAuthority {}.data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
     );
 
-    let (token_account, ixs) = if token_mint == &native_mint::id() {
-        let keypair = Keypair::new();
-        let token_account = keypair.pubkey();
-        let lamports = rpc_client
-            .get_minimum_balance_for_rent_exemption(TokenAccount::LEN)
-            .unwrap();
-        (
-            Some(keypair),
-            vec![
-                create_account(
-                    &signer.pubkey(),
-                    &token_account,
-                    lamports,
-                    TokenAccount::LEN as u64,
-                    &spl_token::id(),
-                ),
-                initialize_account(
-                    &spl_token::id(),
-                    &token_account,
-                    token_mint,
-                    &signer.pubkey(),
-                )
-                .unwrap(),
-                Instruction {
-                    accounts: Unwrap {
-                        token_mint: *token_mint,
-                        token_account,
-                        program_authority: program_authority,
-                        confidential_mint,
-                        confidential_token_account,
-                        token_vault,
-                        authority: signer.pubkey(),
-                        payer: signer.pubkey(),
-                        token_program: spl_token::ID,
-                        token_extensions_program: token_2022::ID,
-                    }
-                    .to_account_metas(None),
-                    program_id: ctw_token::ID,
-                    data: ctw_token::instruction::Unwrap { amount }.data(),
-                },
-                close_account(
-                    &spl_token::id(),
-                    &token_account,
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+fn set_unwrap_limit(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    unwrap_limit: Option<u64>,
+    unwrap_window_slots: u64,
+) -> Result<Signature, Error> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: SetUnwrapLimit {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            authority: signer.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetUnwrapLimit {
+            unwrap_limit,
+            unwrap_window_slots,
+        }
+        .data(),
+    };
+    let latest_blockhash = match rpc_client.get_latest_blockhash() {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(Error::Client(e));
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
+        Ok(s) => Ok(s),
+        Err(e) => Err(map_client_error(e)),
+    }
+}
+
+/// Withdraws fees from the fee vault into the given token account, or the signer's associated token account,
+/// creating the latter if it does not exist yet.
+fn withdraw_fees(
+    rpc_client: &RpcClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    destination: Option<Pubkey>,
+    amount: u64,
+) -> Result<Signature, Error> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, variant);
+    let token_program = get_token_program(rpc_client, token_mint)?;
+
+    let mut ixs = vec![];
+    let destination = match destination {
+        Some(d) => d,
+        None => {
+            let destination = get_associated_token_address_with_program_id(
+                &signer.pubkey(),
+                token_mint,
+                &token_program,
+            );
+            if rpc_client.get_account(&destination).is_err() {
+                ixs.push(create_associated_token_account(
                     &signer.pubkey(),
                     &signer.pubkey(),
-                    &[],
-                )
-                .unwrap(),
-            ],
-        )
-    } else {
-        let token_account = get_associated_token_address(&signer.pubkey(), token_mint);
-        (
-            None,
-            vec![Instruction {
-                accounts: Unwrap {
-                    token_mint: *token_mint,
-                    token_account,
-                    program_authority: program_authority,
-                    confidential_mint,
-                    confidential_token_account,
-                    token_vault,
-                    authority: signer.pubkey(),
-                    payer: signer.pubkey(),
-                    token_program: spl_token::ID,
-                    token_extensions_program: token_2022::ID,
-                }
-                .to_account_metas(None),
-                program_id: ctw_token::ID,
-                data: ctw_token::instruction::Unwrap { amount }.data(),
-            }],
-        )
+                    token_mint,
+                    &token_program,
+                ));
+            }
+            destination
+        }
     };
 
+    let transfer_hook_accounts = get_transfer_hook_accounts(
+        rpc_client,
+        &token_program,
+        &fee_vault,
+        token_mint,
+        &destination,
+        &wrapper_authority,
+        amount,
+    )?;
+
+    ixs.push(Instruction {
+        accounts: WithdrawFees {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            fee_vault,
+            destination,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            token_program,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::WithdrawFees { amount }.data(),
+    });
+
     let latest_blockhash = match rpc_client.get_latest_blockhash() {
         Ok(lb) => lb,
         Err(e) => {
             return Err(Error::Client(e));
         }
     };
-
-    let mut tx = Transaction::new_with_payer(&ixs, Some(&signer.pubkey()));
-    tx.partial_sign(&[signer], latest_blockhash);
-
-    if let Some(signer) = token_account {
-        tx.partial_sign(&[&signer], latest_blockhash);
-    }
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
 
     match rpc_client.send_and_confirm_transaction_with_spinner(&tx) {
         Ok(s) => Ok(s),
-        Err(e) => Err(Error::Client(e)),
+        Err(e) => Err(map_client_error(e)),
     }
 }
 
@@ -530,8 +3215,9 @@ fn create_and_configure_confidential_token_account(
     rpc_client: &RpcClient,
     signer: &Keypair,
     token_mint: &Pubkey,
+    variant: u16,
 ) -> Result<Signature, Error> {
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -612,9 +3298,10 @@ fn withdraw_and_verify(
     rpc_client: &RpcClient,
     signer: &Keypair,
     token_mint: &Pubkey,
+    variant: u16,
     amount: u64,
 ) -> Result<Signature, Error> {
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -718,9 +3405,10 @@ fn post_wrap(
     rpc_client: &RpcClient,
     signer: &Keypair,
     token_mint: &Pubkey,
+    variant: u16,
     amount: u64,
 ) -> Result<Signature, Error> {
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
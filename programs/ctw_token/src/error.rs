@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum CtwError {
+    #[msg("The SPL Token Mint has a permanent delegate which could transfer or burn the vault's tokens")]
+    PermanentDelegateNotSupported,
+    // No longer returned since transfer hooks are run with the extra accounts they require, but kept so that the
+    // codes of the errors after it do not change
+    #[msg("The SPL Token Mint has a transfer hook which could block transfers into or out of the vault")]
+    TransferHookNotSupported,
+    #[msg("The SPL Token Mint is non-transferable and cannot be deposited into the vault")]
+    NonTransferableNotSupported,
+    #[msg("The SPL Token Mint initializes new token accounts as frozen")]
+    DefaultAccountStateFrozenNotSupported,
+    #[msg("The SPL Token Mint accrues interest which breaks the 1:1 equivalence with the wrapped token")]
+    InterestBearingNotSupported,
+    #[msg("The SPL Token Mint has a close authority and could be closed and re-created")]
+    MintCloseAuthorityNotSupported,
+    #[msg("The SPL Token Mint has an extension which is not supported")]
+    UnsupportedMintExtension,
+    #[msg("The SPL Token Mint already has a Confidential Wrapped Token Mint")]
+    MintAlreadyWrapped,
+    #[msg("The amount must be greater than zero")]
+    ZeroAmount,
+    #[msg("The confidential token account does not have enough public balance")]
+    InsufficientPublicBalance,
+    #[msg("The vault does not hold enough tokens to back the Confidential Wrapped Token supply")]
+    VaultInvariantViolated,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("The wrapper authority is not the confidential transfer authority of the Confidential Wrapped Token Mint")]
+    ConfidentialTransferAuthorityNotProgram,
+    #[msg("The SPL Token Account is frozen")]
+    TokenAccountFrozen,
+    #[msg("The signer is not the freeze authority of the SPL Token Mint")]
+    FreezeAuthorityMismatch,
+    #[msg("The wrapper authority is not the freeze authority of the Confidential Wrapped Token Mint")]
+    FreezeAuthorityNotProgram,
+    #[msg("Wrapping and unwrapping are paused")]
+    Paused,
+    #[msg("The signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("The signer is not the upgrade authority of the program")]
+    NotUpgradeAuthority,
+    #[msg("The fee exceeds the maximum fee")]
+    FeeTooHigh,
+    #[msg("The amount is below the minimum wrap amount")]
+    WrapAmountTooSmall,
+    #[msg("The amount is above the maximum wrap amount per transaction")]
+    WrapAmountTooLarge,
+    #[msg("Wrapping would exceed the maximum supply of the Confidential Wrapped Token")]
+    MaxSupplyExceeded,
+    #[msg("The minimum wrap amount exceeds the maximum wrap amount per transaction")]
+    InvalidLimits,
+    #[msg("The amount exceeds the remaining unwrap capacity of the current window")]
+    UnwrapLimitExceeded,
+    #[msg("The unwrap window must be at least one slot")]
+    InvalidUnwrapWindow,
+    #[msg("The supply of the Confidential Wrapped Token is not zero")]
+    SupplyNotZero,
+    #[msg("The vault or the fee vault still holds SPL Tokens")]
+    VaultNotEmpty,
+    #[msg("No treasury is set for the wrapper")]
+    TreasuryNotSet,
+    #[msg("The token account is not the treasury of the wrapper")]
+    InvalidTreasury,
+    #[msg("The vault holds no surplus")]
+    NoSurplus,
+    #[msg("Only the mint authority or an admin may choose the auditor")]
+    AuditorNotAuthorized,
+    #[msg("The wrapper already has its own authority")]
+    AuthorityAlreadyMigrated,
+    #[msg("The Confidential Wrapped Token Mint was not created by a legacy wrapper")]
+    NotLegacyWrapper,
+    #[msg("Only the mint authority or the admin of the program may choose the wrapper admin")]
+    AdminNotAuthorized,
+    #[msg("Only the mint authority or the admin of the program may require approval")]
+    ApprovalNotAuthorized,
+    #[msg("The freeze authority of the Confidential Wrapped Token Mint was revoked and cannot be restored")]
+    FreezeAuthorityRevoked,
+    #[msg("The Confidential Token Account has not been approved yet")]
+    AccountNotApproved,
+}
+
+#[cfg(feature = "client")]
+impl TryFrom<u32> for CtwError {
+    type Error = u32;
+
+    /// Decode an error from the custom error code of a failed instruction.
+    fn try_from(code: u32) -> std::result::Result<Self, Self::Error> {
+        // Every new error must be added here, which the tests check against the IDL
+        [
+            CtwError::PermanentDelegateNotSupported,
+            CtwError::TransferHookNotSupported,
+            CtwError::NonTransferableNotSupported,
+            CtwError::DefaultAccountStateFrozenNotSupported,
+            CtwError::InterestBearingNotSupported,
+            CtwError::MintCloseAuthorityNotSupported,
+            CtwError::UnsupportedMintExtension,
+            CtwError::MintAlreadyWrapped,
+            CtwError::ZeroAmount,
+            CtwError::InsufficientPublicBalance,
+            CtwError::VaultInvariantViolated,
+            CtwError::MathOverflow,
+            CtwError::ConfidentialTransferAuthorityNotProgram,
+            CtwError::TokenAccountFrozen,
+            CtwError::FreezeAuthorityMismatch,
+            CtwError::FreezeAuthorityNotProgram,
+            CtwError::Paused,
+            CtwError::Unauthorized,
+            CtwError::NotUpgradeAuthority,
+            CtwError::FeeTooHigh,
+            CtwError::WrapAmountTooSmall,
+            CtwError::WrapAmountTooLarge,
+            CtwError::MaxSupplyExceeded,
+            CtwError::InvalidLimits,
+            CtwError::UnwrapLimitExceeded,
+            CtwError::InvalidUnwrapWindow,
+            CtwError::SupplyNotZero,
+            CtwError::VaultNotEmpty,
+            CtwError::TreasuryNotSet,
+            CtwError::InvalidTreasury,
+            CtwError::NoSurplus,
+            CtwError::AuditorNotAuthorized,
+            CtwError::AuthorityAlreadyMigrated,
+            CtwError::NotLegacyWrapper,
+            CtwError::AdminNotAuthorized,
+            CtwError::ApprovalNotAuthorized,
+            CtwError::FreezeAuthorityRevoked,
+            CtwError::AccountNotApproved,
+        ]
+        .into_iter()
+        .find(|e| u32::from(*e) == code)
+        .ok_or(code)
+    }
+}
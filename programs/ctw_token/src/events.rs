@@ -0,0 +1,540 @@
+use anchor_lang::prelude::*;
+#[cfg(feature = "client")]
+use anchor_lang::Discriminator;
+
+/// Emitted when a Confidential Wrapped Token Mint is initialized for an SPL Token Mint.
+#[event]
+#[derive(Debug, Clone)]
+pub struct WrapperInitialized {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Token Program which owns the SPL Token Mint.
+    pub token_program: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The vault holding the wrapped SPL Tokens.
+    pub token_vault: Pubkey,
+    /// The variant of the wrapper among the wrappers of the SPL Token Mint.
+    pub variant: u16,
+    /// The payer which initialized the wrapper.
+    pub payer: Pubkey,
+    /// The slot at which the wrapper was initialized.
+    pub slot: u64,
+}
+
+/// Emitted when an SPL Token is wrapped into its Confidential Wrapped Token.
+#[event]
+#[derive(Debug, Clone)]
+pub struct Wrapped {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The owner of the wrapped tokens.
+    pub owner: Pubkey,
+    /// The Confidential Token Account credited with the minted tokens.
+    pub recipient: Pubkey,
+    /// The amount of Confidential Wrapped Token minted, which excludes any withheld transfer fee and the wrap fee.
+    pub amount: u64,
+    /// The wrap fee deducted from the amount received by the vault and moved into the fee vault.
+    pub fee: u64,
+    /// The amount held by the vault after wrapping.
+    pub vault_balance: u64,
+    /// The slot at which the tokens were wrapped.
+    pub slot: u64,
+}
+
+/// Emitted when a Confidential Wrapped Token is unwrapped back into its SPL Token.
+#[event]
+#[derive(Debug, Clone)]
+pub struct Unwrapped {
+    /// The SPL Token Mint being unwrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The owner of the unwrapped tokens.
+    pub owner: Pubkey,
+    /// The amount of Confidential Wrapped Token burned, which includes the unwrap fee.
+    pub amount: u64,
+    /// The unwrap fee deducted from the amount burned and moved into the fee vault.
+    pub fee: u64,
+    /// The amount held by the vault after unwrapping.
+    pub vault_balance: u64,
+    /// The slot at which the tokens were unwrapped.
+    pub slot: u64,
+}
+
+/// Emitted when the auditor of a Confidential Wrapped Token Mint is rotated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct AuditorRotated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The previous auditor ElGamal public key, all zeroes if there was none.
+    pub previous_auditor_elgamal_pubkey: [u8; 32],
+    /// The new auditor ElGamal public key.
+    pub auditor_elgamal_pubkey: [u8; 32],
+    /// The slot at which the auditor was rotated.
+    pub slot: u64,
+}
+
+/// Emitted when wrapping and unwrapping are paused or unpaused, either for a wrapper or for every wrapper.
+#[event]
+#[derive(Debug, Clone)]
+pub struct PauseUpdated {
+    /// The SPL Token Mint of the wrapper, or `None` if every wrapper is affected.
+    pub token_mint: Option<Pubkey>,
+    /// Whether wrapping and unwrapping are now paused.
+    pub paused: bool,
+    /// The admin or guardian which updated the pause.
+    pub authority: Pubkey,
+    /// The slot at which the pause was updated.
+    pub slot: u64,
+}
+
+/// Emitted when the admin of the program accepts the role proposed through `transfer_admin`.
+#[event]
+#[derive(Debug, Clone)]
+pub struct AdminTransferred {
+    /// The previous admin of the program.
+    pub previous_admin: Pubkey,
+    /// The new admin of the program.
+    pub admin: Pubkey,
+    /// The slot at which the admin was transferred.
+    pub slot: u64,
+}
+
+/// Emitted when the fees collected by a wrapper are withdrawn from its fee vault.
+#[event]
+#[derive(Debug, Clone)]
+pub struct FeesWithdrawn {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The token account receiving the fees.
+    pub destination: Pubkey,
+    /// The amount withdrawn from the fee vault.
+    pub amount: u64,
+    /// The slot at which the fees were withdrawn.
+    pub slot: u64,
+}
+
+/// Emitted when the surplus of a vault is swept into the treasury of its wrapper.
+#[event]
+#[derive(Debug, Clone)]
+pub struct SurplusSwept {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The token account receiving the surplus.
+    pub treasury: Pubkey,
+    /// The amount held by the vault in excess of the supply which was swept.
+    pub amount: u64,
+    /// The slot at which the surplus was swept.
+    pub slot: u64,
+}
+
+/// Emitted when a wrapper is closed and its Confidential Wrapped Token Mint disabled.
+#[event]
+#[derive(Debug, Clone)]
+pub struct WrapperClosed {
+    /// The SPL Token Mint which was wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint, which can no longer be minted.
+    pub confidential_mint: Pubkey,
+    /// The admin which closed the wrapper.
+    pub admin: Pubkey,
+    /// The slot at which the wrapper was closed.
+    pub slot: u64,
+}
+
+/// Emitted when a wrapper created under the authority shared by every wrapper is moved to its own authority.
+#[event]
+#[derive(Debug, Clone)]
+pub struct AuthorityMigrated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The authority of the wrapper, which now mints the Confidential Wrapped Token and owns the vaults.
+    pub wrapper_authority: Pubkey,
+    /// The vault of the wrapper authority, now holding the wrapped SPL Tokens.
+    pub token_vault: Pubkey,
+    /// The amount moved from the previous vault.
+    pub amount: u64,
+    /// The slot at which the wrapper was migrated.
+    pub slot: u64,
+}
+
+/// Emitted when the Wrapper Config of a wrapper initialized before it existed is reconstructed.
+#[event]
+#[derive(Debug, Clone)]
+pub struct LegacyWrapperAdopted {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The vault holding the wrapped SPL Tokens.
+    pub token_vault: Pubkey,
+    /// The supply of the Confidential Wrapped Token Mint, recorded as the total wrapped.
+    pub supply: u64,
+    /// The auditor ElGamal public key of the Confidential Wrapped Token Mint, all zeroes if there is none.
+    pub auditor_elgamal_pubkey: [u8; 32],
+    /// The freeze authority of the Confidential Wrapped Token Mint.
+    pub freeze_authority: Option<Pubkey>,
+    /// The slot at which the wrapper was adopted.
+    pub slot: u64,
+}
+
+/// Emitted when the wrap and unwrap fees of a wrapper are updated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct FeesUpdated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The new fee, in basis points, deducted from the SPL Tokens being wrapped.
+    pub wrap_fee_bps: u16,
+    /// The new fee, in basis points, deducted from the SPL Tokens being unwrapped.
+    pub unwrap_fee_bps: u16,
+    /// The admin which updated the fees.
+    pub admin: Pubkey,
+    /// The slot at which the fees were updated.
+    pub slot: u64,
+}
+
+/// Emitted when the limits of the amount wrapped into a wrapper are updated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct LimitsUpdated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The new maximum supply of the Confidential Wrapped Token Mint, unlimited if there is none.
+    pub max_supply: Option<u64>,
+    /// The new maximum amount wrapped in a single instruction, unlimited if there is none.
+    pub max_wrap_per_tx: Option<u64>,
+    /// The new minimum amount wrapped in a single instruction.
+    pub min_wrap_amount: u64,
+    /// The admin which updated the limits.
+    pub admin: Pubkey,
+    /// The slot at which the limits were updated.
+    pub slot: u64,
+}
+
+/// Emitted when the unwrap limit of a wrapper is updated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct UnwrapLimitUpdated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The new maximum amount which may be unwrapped within the window, unlimited if there is none.
+    pub unwrap_limit: Option<u64>,
+    /// The new length, in slots, of the window over which the unwrap limit applies.
+    pub unwrap_window_slots: u64,
+    /// The admin or guardian which updated the unwrap limit.
+    pub authority: Pubkey,
+    /// The slot at which the unwrap limit was updated.
+    pub slot: u64,
+}
+
+/// Emitted when the treasury of a wrapper is updated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct TreasuryUpdated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The new token account receiving the surplus of the vault, if any.
+    pub treasury: Option<Pubkey>,
+    /// The admin which updated the treasury.
+    pub admin: Pubkey,
+    /// The slot at which the treasury was updated.
+    pub slot: u64,
+}
+
+/// Emitted when the approval policy and the auditor of a Confidential Wrapped Token Mint are updated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct ConfidentialMintUpdated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// Whether new CT-enabled Token Accounts are now approved without the confidential transfer authority.
+    pub auto_approve_new_accounts: bool,
+    /// The new auditor ElGamal public key, all zeroes if there is none.
+    pub auditor_elgamal_pubkey: [u8; 32],
+    /// The admin which updated the Confidential Wrapped Token Mint.
+    pub admin: Pubkey,
+    /// The slot at which the Confidential Wrapped Token Mint was updated.
+    pub slot: u64,
+}
+
+/// Emitted when a CT-enabled Token Account of a Confidential Wrapped Token Mint requiring approval is approved.
+#[event]
+#[derive(Debug, Clone)]
+pub struct AccountApproved {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The approved Confidential Token Account.
+    pub confidential_token_account: Pubkey,
+    /// The admin which approved the account.
+    pub admin: Pubkey,
+    /// The slot at which the account was approved.
+    pub slot: u64,
+}
+
+/// Emitted when a Confidential Token Account is frozen or thawed on behalf of the SPL Token Mint's freeze authority.
+#[event]
+#[derive(Debug, Clone)]
+pub struct AccountFreezeUpdated {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The frozen or thawed Confidential Token Account.
+    pub confidential_token_account: Pubkey,
+    /// Whether the account is now frozen.
+    pub frozen: bool,
+    /// The freeze authority of the SPL Token Mint which froze or thawed the account.
+    pub freeze_authority: Pubkey,
+    /// The slot at which the account was frozen or thawed.
+    pub slot: u64,
+}
+
+/// Emitted when the freeze authority of a Confidential Wrapped Token Mint is synced with its SPL Token Mint.
+#[event]
+#[derive(Debug, Clone)]
+pub struct FreezeAuthoritySynced {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The new freeze authority of the Confidential Wrapped Token Mint, if any.
+    pub freeze_authority: Option<Pubkey>,
+    /// The slot at which the freeze authority was synced.
+    pub slot: u64,
+}
+
+/// Emitted when the global state of the program is initialized by its upgrade authority.
+#[event]
+#[derive(Debug, Clone)]
+pub struct GlobalInitialized {
+    /// The admin of the program.
+    pub admin: Pubkey,
+    /// The guardian of the program.
+    pub guardian: Pubkey,
+    /// The slot at which the global state was initialized.
+    pub slot: u64,
+}
+
+/// Emitted when the admin of the program proposes a new admin, which has yet to accept the role.
+#[event]
+#[derive(Debug, Clone)]
+pub struct AdminProposed {
+    /// The current admin of the program.
+    pub admin: Pubkey,
+    /// The proposed admin of the program.
+    pub pending_admin: Pubkey,
+    /// The slot at which the admin was proposed.
+    pub slot: u64,
+}
+
+/// Emitted when the guardian of the program is replaced.
+#[event]
+#[derive(Debug, Clone)]
+pub struct GuardianUpdated {
+    /// The previous guardian of the program.
+    pub previous_guardian: Pubkey,
+    /// The new guardian of the program.
+    pub guardian: Pubkey,
+    /// The slot at which the guardian was replaced.
+    pub slot: u64,
+}
+
+/// Emitted when the policy applied to new wrappers is updated.
+#[event]
+#[derive(Debug, Clone)]
+pub struct DefaultPolicyUpdated {
+    /// The new admin of new wrappers initialized without one, if any.
+    pub default_wrapper_admin: Option<Pubkey>,
+    /// Whether new wrappers now start paused.
+    pub default_paused: bool,
+    /// The slot at which the policy was updated.
+    pub slot: u64,
+}
+
+/// An event emitted by the Confidential Wrapped Token Program.
+#[cfg(feature = "client")]
+#[derive(Debug, Clone)]
+pub enum CtwEvent {
+    WrapperInitialized(WrapperInitialized),
+    Wrapped(Wrapped),
+    Unwrapped(Unwrapped),
+    AuditorRotated(AuditorRotated),
+    PauseUpdated(PauseUpdated),
+    AdminTransferred(AdminTransferred),
+    FeesWithdrawn(FeesWithdrawn),
+    SurplusSwept(SurplusSwept),
+    WrapperClosed(WrapperClosed),
+    AuthorityMigrated(AuthorityMigrated),
+    LegacyWrapperAdopted(LegacyWrapperAdopted),
+    FeesUpdated(FeesUpdated),
+    LimitsUpdated(LimitsUpdated),
+    UnwrapLimitUpdated(UnwrapLimitUpdated),
+    TreasuryUpdated(TreasuryUpdated),
+    ConfidentialMintUpdated(ConfidentialMintUpdated),
+    AccountApproved(AccountApproved),
+    AccountFreezeUpdated(AccountFreezeUpdated),
+    FreezeAuthoritySynced(FreezeAuthoritySynced),
+    GlobalInitialized(GlobalInitialized),
+    AdminProposed(AdminProposed),
+    GuardianUpdated(GuardianUpdated),
+    DefaultPolicyUpdated(DefaultPolicyUpdated),
+}
+
+#[cfg(feature = "client")]
+impl CtwEvent {
+    const PROGRAM: &'static str = "Program ";
+    const PROGRAM_DATA: &'static str = "Program data: ";
+
+    /// Decode an event from its serialized data, prefixed by the event discriminator.
+    pub fn try_from_data(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+        let (discriminator, mut event) = data.split_at(8);
+
+        if discriminator == WrapperInitialized::DISCRIMINATOR {
+            WrapperInitialized::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::WrapperInitialized)
+        } else if discriminator == Wrapped::DISCRIMINATOR {
+            Wrapped::deserialize(&mut event).ok().map(CtwEvent::Wrapped)
+        } else if discriminator == Unwrapped::DISCRIMINATOR {
+            Unwrapped::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::Unwrapped)
+        } else if discriminator == AuditorRotated::DISCRIMINATOR {
+            AuditorRotated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::AuditorRotated)
+        } else if discriminator == PauseUpdated::DISCRIMINATOR {
+            PauseUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::PauseUpdated)
+        } else if discriminator == AdminTransferred::DISCRIMINATOR {
+            AdminTransferred::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::AdminTransferred)
+        } else if discriminator == FeesWithdrawn::DISCRIMINATOR {
+            FeesWithdrawn::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::FeesWithdrawn)
+        } else if discriminator == SurplusSwept::DISCRIMINATOR {
+            SurplusSwept::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::SurplusSwept)
+        } else if discriminator == WrapperClosed::DISCRIMINATOR {
+            WrapperClosed::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::WrapperClosed)
+        } else if discriminator == AuthorityMigrated::DISCRIMINATOR {
+            AuthorityMigrated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::AuthorityMigrated)
+        } else if discriminator == LegacyWrapperAdopted::DISCRIMINATOR {
+            LegacyWrapperAdopted::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::LegacyWrapperAdopted)
+        } else if discriminator == FeesUpdated::DISCRIMINATOR {
+            FeesUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::FeesUpdated)
+        } else if discriminator == LimitsUpdated::DISCRIMINATOR {
+            LimitsUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::LimitsUpdated)
+        } else if discriminator == UnwrapLimitUpdated::DISCRIMINATOR {
+            UnwrapLimitUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::UnwrapLimitUpdated)
+        } else if discriminator == TreasuryUpdated::DISCRIMINATOR {
+            TreasuryUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::TreasuryUpdated)
+        } else if discriminator == ConfidentialMintUpdated::DISCRIMINATOR {
+            ConfidentialMintUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::ConfidentialMintUpdated)
+        } else if discriminator == AccountApproved::DISCRIMINATOR {
+            AccountApproved::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::AccountApproved)
+        } else if discriminator == AccountFreezeUpdated::DISCRIMINATOR {
+            AccountFreezeUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::AccountFreezeUpdated)
+        } else if discriminator == FreezeAuthoritySynced::DISCRIMINATOR {
+            FreezeAuthoritySynced::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::FreezeAuthoritySynced)
+        } else if discriminator == GlobalInitialized::DISCRIMINATOR {
+            GlobalInitialized::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::GlobalInitialized)
+        } else if discriminator == AdminProposed::DISCRIMINATOR {
+            AdminProposed::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::AdminProposed)
+        } else if discriminator == GuardianUpdated::DISCRIMINATOR {
+            GuardianUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::GuardianUpdated)
+        } else if discriminator == DefaultPolicyUpdated::DISCRIMINATOR {
+            DefaultPolicyUpdated::deserialize(&mut event)
+                .ok()
+                .map(CtwEvent::DefaultPolicyUpdated)
+        } else {
+            None
+        }
+    }
+
+    /// Decode all the events found in the log messages of a transaction.
+    ///
+    /// The invocations are followed through the logs so that only the data logged by the Confidential Wrapped Token
+    /// Program itself is decoded, as any other program of the transaction, including one it invokes, could log
+    /// the data of a fake event.
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Vec<Self> {
+        let mut invocations = Vec::new();
+        let mut events = Vec::new();
+
+        for log in logs.iter().map(|log| log.as_ref()) {
+            if let Some(data) = log.strip_prefix(Self::PROGRAM_DATA) {
+                if invocations.last() != Some(&crate::ID) {
+                    continue;
+                }
+                if let Some(event) = anchor_lang::__private::base64::decode(data)
+                    .ok()
+                    .and_then(|data| Self::try_from_data(&data))
+                {
+                    events.push(event);
+                }
+            } else if let Some(log) = log.strip_prefix(Self::PROGRAM) {
+                // Programs can only log after "Program log: ", so a program id is always logged by the runtime
+                let mut words = log.split(' ');
+                let program_id = match words.next().and_then(|word| word.parse::<Pubkey>().ok()) {
+                    Some(program_id) => program_id,
+                    None => continue,
+                };
+                match words.next() {
+                    Some("invoke") => invocations.push(program_id),
+                    Some("success") | Some("failed:") => {
+                        invocations.pop();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        events
+    }
+}
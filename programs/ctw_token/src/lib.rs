@@ -5,24 +5,53 @@ use {
     },
     anchor_spl::{
         associated_token::AssociatedToken,
-        token_2022::{mint_to, MintTo},
-        token_interface::{initialize_mint2, Mint, TokenInterface, TokenAccount, transfer_checked, TransferChecked},
+        token_2022::{mint_to, spl_token_2022::instruction::AuthorityType, MintTo},
+        token_interface::{
+            initialize_account3, initialize_mint2, InitializeAccount3, Mint, TokenAccount,
+            TokenInterface, TransferChecked,
+        },
+    },
+    solana_program::{
+        instruction::Instruction,
+        program::{invoke, invoke_signed},
+        program_option::COption,
     },
-    solana_program::{instruction::Instruction, program::invoke, program_option::COption},
+    spl_tlv_account_resolution::state::ExtraAccountMetaList,
     spl_token_2022::{
         check_program_account,
         extension::{
-            confidential_transfer::instruction::{
-                ConfidentialTransferInstruction, InitializeMintData, deposit
+            confidential_transfer::{
+                instruction::{
+                    ConfidentialTransferInstruction, InitializeMintData, UpdateMintData, deposit
+                },
+                ConfidentialTransferAccount, ConfidentialTransferMint,
             },
-            ExtensionType,
+            default_account_state::DefaultAccountState,
+            mint_close_authority::MintCloseAuthority,
+            permanent_delegate::PermanentDelegate,
+            transfer_fee::TransferFeeConfig,
+            transfer_hook,
+            BaseStateWithExtensions, ExtensionType, StateWithExtensions,
         },
         instruction::{burn, TokenInstruction},
         solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
-        state::{Mint as MintWithExtensions},
+        state::{Account as AccountWithExtensions, AccountState, Mint as MintWithExtensions},
     }, 
+    spl_transfer_hook_interface::{
+        error::TransferHookError,
+        get_extra_account_metas_address,
+        instruction::{execute, ExecuteInstruction},
+    },
 };
 
+pub mod error;
+pub mod events;
+pub mod state;
+
+pub use error::*;
+pub use events::*;
+pub use state::*;
+
 /// Utility function for encoding instruction data
 fn encode_instruction<T: Into<u8>, D: Pod>(
     token_program_id: &Pubkey,
@@ -67,6 +96,526 @@ fn initialize_confidential_transfer(
     ))
 }
 
+/// Create a `UpdateMint` instruction
+/// This fn within spl-token-2022 is marked with target not os = solana,
+/// which makes it impossible for programs to update confidential transfers via cpi.
+fn update_confidential_transfer(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<ElGamalPubkey>,
+) -> Result<Instruction> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, true),
+    ];
+
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::ConfidentialTransferExtension,
+        ConfidentialTransferInstruction::UpdateMint,
+        &UpdateMintData {
+            auto_approve_new_accounts: auto_approve_new_accounts.into(),
+            auditor_elgamal_pubkey: auditor_elgamal_pubkey.try_into()?,
+        },
+    ))
+}
+
+/// Check that the SPL Token Mint does not have any extension which would allow draining or freezing the vault,
+/// or which would break the 1:1 equivalence between the SPL Token and the Confidential Wrapped Token.
+fn check_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if mint.owner != &spl_token_2022::id() {
+        return Ok(());
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintWithExtensions>::unpack(&mint_data)?;
+
+    let extension_types = mint
+        .get_extension_types()
+        .map_err(|_| error!(CtwError::UnsupportedMintExtension))?;
+
+    for extension_type in extension_types {
+        match extension_type {
+            ExtensionType::PermanentDelegate => {
+                let permanent_delegate = mint.get_extension::<PermanentDelegate>()?;
+                if Option::<Pubkey>::from(permanent_delegate.delegate).is_some() {
+                    return err!(CtwError::PermanentDelegateNotSupported);
+                }
+            }
+            ExtensionType::NonTransferable => {
+                return err!(CtwError::NonTransferableNotSupported);
+            }
+            ExtensionType::DefaultAccountState => {
+                let default_account_state = mint.get_extension::<DefaultAccountState>()?;
+                if default_account_state.state == AccountState::Frozen as u8 {
+                    return err!(CtwError::DefaultAccountStateFrozenNotSupported);
+                }
+            }
+            ExtensionType::InterestBearingConfig => {
+                return err!(CtwError::InterestBearingNotSupported);
+            }
+            ExtensionType::MintCloseAuthority => {
+                let mint_close_authority = mint.get_extension::<MintCloseAuthority>()?;
+                if Option::<Pubkey>::from(mint_close_authority.close_authority).is_some() {
+                    return err!(CtwError::MintCloseAuthorityNotSupported);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculate the fee withheld when transferring the given amount of a mint with the `TransferFeeConfig` extension.
+/// Returns `None` if the mint does not charge transfer fees.
+fn calculate_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<Option<u64>> {
+    if mint.owner != &spl_token_2022::id() {
+        return Ok(None);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintWithExtensions>::unpack(&mint_data)?;
+
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => {
+            let fee = transfer_fee_config
+                .calculate_epoch_fee(Clock::get()?.epoch, amount)
+                .ok_or(CtwError::MathOverflow)?;
+            Ok(Some(fee))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+/// Transfer the given amount of an SPL Token, using `TransferCheckedWithFee` if the mint charges a transfer fee
+/// so that the Token Extensions Program asserts the fee being withheld is the one we expect.
+///
+/// If the mint has a transfer hook, the extra accounts it requires for this very transfer are resolved from the
+/// remaining accounts of the given [`CpiContext`] through the hook's `ExtraAccountMetaList`, so an instruction making
+/// several transfers must be given the extra accounts of each of them.
+fn transfer_checked_with_fee<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let mut instruction = match calculate_transfer_fee(&ctx.accounts.mint, amount)? {
+        Some(fee) => spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee(
+            ctx.program.key,
+            ctx.accounts.from.key,
+            ctx.accounts.mint.key,
+            ctx.accounts.to.key,
+            ctx.accounts.authority.key,
+            &[],
+            amount,
+            decimals,
+            fee,
+        )?,
+        None => spl_token_2022::instruction::transfer_checked(
+            ctx.program.key,
+            ctx.accounts.from.key,
+            ctx.accounts.mint.key,
+            ctx.accounts.to.key,
+            ctx.accounts.authority.key,
+            &[],
+            amount,
+            decimals,
+        )?,
+    };
+
+    let mut account_infos = vec![
+        ctx.accounts.from,
+        ctx.accounts.mint.clone(),
+        ctx.accounts.to,
+        ctx.accounts.authority,
+    ];
+
+    if let Some(transfer_hook_program_id) = get_transfer_hook_program_id(&ctx.accounts.mint)? {
+        let validation_pubkey =
+            get_extra_account_metas_address(ctx.accounts.mint.key, &transfer_hook_program_id);
+        let validation_info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|info| info.key == &validation_pubkey)
+            .ok_or(ProgramError::from(TransferHookError::IncorrectAccount))?;
+        let program_info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|info| info.key == &transfer_hook_program_id)
+            .ok_or(ProgramError::from(TransferHookError::IncorrectAccount))?;
+
+        // The extra accounts are resolved for the `Execute` instruction of the hook, as the Token Extensions
+        // Program does, since accounts derived from seeds are derived from the program id of the hook
+        let mut execute_instruction = execute(
+            &transfer_hook_program_id,
+            account_infos[0].key,
+            account_infos[1].key,
+            account_infos[2].key,
+            account_infos[3].key,
+            &validation_pubkey,
+            amount,
+        );
+        let mut execute_account_infos = account_infos.clone();
+        execute_account_infos.push(validation_info.clone());
+        ExtraAccountMetaList::add_to_cpi_instruction::<ExecuteInstruction>(
+            &mut execute_instruction,
+            &mut execute_account_infos,
+            &validation_info.try_borrow_data()?,
+            &ctx.remaining_accounts,
+        )?;
+
+        instruction
+            .accounts
+            .extend(execute_instruction.accounts.into_iter().skip(5));
+        account_infos.extend(execute_account_infos.into_iter().skip(5));
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(validation_pubkey, false));
+        account_infos.push(validation_info.clone());
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(transfer_hook_program_id, false));
+        account_infos.push(program_info.clone());
+    }
+
+    invoke_signed(&instruction, &account_infos, ctx.signer_seeds)?;
+
+    Ok(())
+}
+
+/// Calculate the space of a token account for the given mint, including the extensions the mint requires
+/// its token accounts to have, such as the transfer fee amount or the transfer hook account.
+fn get_token_account_len(mint: &AccountInfo) -> Result<usize> {
+    if mint.owner != &spl_token_2022::id() {
+        return Ok(ExtensionType::try_calculate_account_len::<
+            AccountWithExtensions,
+        >(&[])?);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintWithExtensions>::unpack(&mint_data)?;
+    let required_extensions =
+        ExtensionType::get_required_init_account_extensions(&mint.get_extension_types()?);
+
+    Ok(ExtensionType::try_calculate_account_len::<
+        AccountWithExtensions,
+    >(&required_extensions)?)
+}
+
+/// Wrap the given amount into the Confidential Token Account of the given context, depositing the minted tokens
+/// into its confidential balance only if asked to, since doing so requires the signature of its owner.
+fn process_wrap<'info>(
+    ctx: Context<'_, '_, '_, 'info, Wrap<'info>>,
+    amount: u64,
+    deposit_confidential: bool,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_config.paused && !ctx.accounts.wrapper_config.paused,
+        CtwError::Paused
+    );
+    require_gt!(amount, 0, CtwError::ZeroAmount);
+    require_gte!(
+        amount,
+        ctx.accounts.wrapper_config.min_wrap_amount,
+        CtwError::WrapAmountTooSmall
+    );
+    if let Some(max_wrap_per_tx) = ctx.accounts.wrapper_config.max_wrap_per_tx {
+        require_gte!(max_wrap_per_tx, amount, CtwError::WrapAmountTooLarge);
+    }
+    require!(
+        !ctx.accounts.token_account.is_frozen(),
+        CtwError::TokenAccountFrozen
+    );
+
+    // Depositing fails for accounts that were not approved, but tokens left in the public balance must be kept
+    // from unapproved accounts too when the wrapper requires approval
+    if !deposit_confidential && !ctx.accounts.wrapper_config.auto_approve_new_accounts {
+        let confidential_token_account = ctx.accounts.confidential_token_account.to_account_info();
+        let account_data = confidential_token_account.try_borrow_data()?;
+        let account = StateWithExtensions::<AccountWithExtensions>::unpack(&account_data)?;
+        let approved = account
+            .get_extension::<ConfidentialTransferAccount>()
+            .map(|confidential_transfer_account| bool::from(confidential_transfer_account.approved))
+            .unwrap_or(false);
+        require!(approved, CtwError::AccountNotApproved);
+    }
+
+    let vault_amount_before = ctx.accounts.token_vault.amount;
+
+    // Transfer tokens from the source to the program's vault
+    transfer_checked_with_fee(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.token_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Measure what the vault actually received, which excludes any withheld transfer fee
+    ctx.accounts.token_vault.reload()?;
+    let received_amount = ctx
+        .accounts
+        .token_vault
+        .amount
+        .checked_sub(vault_amount_before)
+        .ok_or(CtwError::MathOverflow)?;
+    require_gt!(received_amount, 0, CtwError::ZeroAmount);
+
+    // Move the wrap fee from the vault into the fee vault
+    let fee = calculate_fee(received_amount, ctx.accounts.wrapper_config.wrap_fee_bps)?;
+    let fee_vault_amount_before = ctx.accounts.fee_vault.amount;
+    if fee > 0 {
+        transfer_checked_with_fee(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.fee_vault.to_account_info(),
+                    authority: ctx.accounts.wrapper_authority.to_account_info(),
+                },
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            fee,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        ctx.accounts.token_vault.reload()?;
+        ctx.accounts.fee_vault.reload()?;
+    }
+
+    // Moving the fee may itself be charged a transfer fee, so only what the fee vault received is collected
+    let collected_fee = ctx
+        .accounts
+        .fee_vault
+        .amount
+        .checked_sub(fee_vault_amount_before)
+        .ok_or(CtwError::MathOverflow)?;
+    let wrapped_amount = received_amount
+        .checked_sub(fee)
+        .ok_or(CtwError::MathOverflow)?;
+    require_gt!(wrapped_amount, 0, CtwError::ZeroAmount);
+
+    // The maximum supply applies to what is actually minted
+    if let Some(max_supply) = ctx.accounts.wrapper_config.max_supply {
+        let supply = ctx
+            .accounts
+            .confidential_mint
+            .supply
+            .checked_add(wrapped_amount)
+            .ok_or(CtwError::MathOverflow)?;
+        require_gte!(max_supply, supply, CtwError::MaxSupplyExceeded);
+    }
+
+    // Mint equivalent amount of tokens to the confidential wrapper token account
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_extensions_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.confidential_mint.to_account_info(),
+                to: ctx.accounts.confidential_token_account.to_account_info(),
+                authority: ctx.accounts.wrapper_authority.to_account_info(),
+            },
+            &[&[
+                ctx.accounts.token_mint.key().as_ref(),
+                AUTHORITY_SEED.as_ref(),
+                variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                &[ctx.bumps.wrapper_authority],
+            ]],
+        ),
+        wrapped_amount,
+    )?;
+
+    // Deposit the minted tokens into the confidential balance of the account
+    // OBS: This will still require integrations to call [`ApplyPendingBalance`] afterwards.
+    if deposit_confidential {
+        invoke(
+            &deposit(
+                &ctx.accounts.token_extensions_program.key(),
+                &ctx.accounts.confidential_token_account.key(),
+                &ctx.accounts.confidential_mint.key(),
+                wrapped_amount,
+                ctx.accounts.confidential_mint.decimals,
+                &ctx.accounts.authority.key(),
+                &[],
+            )?,
+            &[
+                ctx.accounts.confidential_token_account.to_account_info(),
+                ctx.accounts.confidential_mint.to_account_info(),
+                ctx.accounts.authority.to_account_info(),
+            ],
+        )?;
+    }
+
+    ctx.accounts.confidential_mint.reload()?;
+    check_backing(&ctx.accounts.token_vault, &ctx.accounts.confidential_mint)?;
+
+    let wrapper_config = &mut ctx.accounts.wrapper_config;
+    wrapper_config.total_wrapped = wrapper_config
+        .total_wrapped
+        .checked_add(wrapped_amount)
+        .ok_or(CtwError::MathOverflow)?;
+    wrapper_config.total_fees = wrapper_config
+        .total_fees
+        .checked_add(collected_fee)
+        .ok_or(CtwError::MathOverflow)?;
+
+    emit!(Wrapped {
+        token_mint: ctx.accounts.token_mint.key(),
+        confidential_mint: ctx.accounts.confidential_mint.key(),
+        owner: ctx.accounts.authority.key(),
+        recipient: ctx.accounts.confidential_token_account.key(),
+        amount: wrapped_amount,
+        fee,
+        vault_balance: ctx.accounts.token_vault.amount,
+        slot: Clock::get()?.slot,
+    });
+
+    Ok(())
+}
+
+/// Create an account owned by the given program at a PDA of this program.
+///
+/// As anyone may send lamports to the PDA beforehand, which makes `CreateAccount` fail, an account which
+/// already holds lamports is topped up to be rent exempt, allocated and assigned instead.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+
+    if lamports == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let required_lamports = rent.saturating_sub(lamports);
+    if required_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        owner,
+    )
+}
+
+/// Check that the given key may replace the auditor of the wrapper with the given one, returning the
+/// [`AuditorPolicy`] of the wrapper afterwards.
+///
+/// Whoever may update the wrapper may keep or remove its auditor, but only the mint authority of the SPL Token Mint,
+/// the admin of the program or the admin of the wrapper, never the payer of a permissionless initialize, may choose
+/// a new one.
+fn check_auditor_authority(
+    wrapper_config: &WrapperConfig,
+    token_mint: &Mint,
+    global_config: &GlobalConfig,
+    key: &Pubkey,
+    auditor_elgamal_pubkey: [u8; 32],
+) -> Result<AuditorPolicy> {
+    if auditor_elgamal_pubkey == [0; 32] {
+        return Ok(AuditorPolicy::None);
+    }
+
+    if auditor_elgamal_pubkey == wrapper_config.auditor_elgamal_pubkey {
+        return Ok(wrapper_config.auditor_policy);
+    }
+
+    let mut auditor_policy =
+        AuditorPolicy::of(key, token_mint.mint_authority.into(), global_config);
+    if auditor_policy == AuditorPolicy::None && *key == wrapper_config.admin {
+        auditor_policy = AuditorPolicy::WrapperAdmin;
+    }
+    require!(
+        auditor_policy != AuditorPolicy::None,
+        CtwError::AuditorNotAuthorized
+    );
+
+    Ok(auditor_policy)
+}
+
+/// Check that the vault holds at least as many tokens as the supply of the Confidential Wrapped Token Mint,
+/// so that every Confidential Wrapped Token remains backed 1:1 by the SPL Token.
+fn check_backing(token_vault: &TokenAccount, confidential_mint: &Mint) -> Result<()> {
+    require_gte!(
+        token_vault.amount,
+        confidential_mint.supply,
+        CtwError::VaultInvariantViolated
+    );
+
+    Ok(())
+}
+
+/// Get the program of the mint's transfer hook, if it has one.
+fn get_transfer_hook_program_id(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    if mint.owner != &spl_token_2022::id() {
+        return Ok(None);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintWithExtensions>::unpack(&mint_data)?;
+
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
 declare_id!("cwTokjpVjxBeytEXomNe5B38EesYsNsXCm3JZC6tmvB");
 
 #[derive(Clone)]
@@ -80,6 +629,22 @@ impl Id for TokenExtensions {
 
 const AUTHORITY_SEED: &'static str = "AUTHORITY";
 const MINT_SEED: &'static str = "MINT";
+const WRAPPER_SEED: &'static str = "WRAPPER";
+const GLOBAL_SEED: &'static str = "GLOBAL";
+const FEE_VAULT_SEED: &'static str = "FEE_VAULT";
+
+/// The seed distinguishing the variants of the wrapper of an SPL Token Mint, which follows the seeds of the
+/// Confidential Wrapped Token Mint, the Wrapper Config, the wrapper authority and the fee vault.
+///
+/// As seeds are concatenated when deriving a program address, the empty seed of the first variant leaves the
+/// addresses of the wrappers created before variants existed unchanged.
+fn variant_seed(variant: u16) -> Vec<u8> {
+    if variant == 0 {
+        vec![]
+    } else {
+        variant.to_le_bytes().to_vec()
+    }
+}
 
 #[program]
 pub mod ctw_token {
@@ -92,50 +657,104 @@ pub mod ctw_token {
     ///
     /// # Notes
     ///
-    /// This implementation does not require any new CT-enabled Token Accounts to be approved and
-    /// are 1:1 equivalents of the SPL Token.
-    pub fn initialize(
-        ctx: Context<Initialize>,
-        auditor_pubkey: [u8; 32], // solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey length is 32 but it doesn't impl Borsh
-    ) -> Result<()> {
+    /// The approval policy, the confidential transfer authority, the auditor and the admin are configured through
+    /// the given [`InitializeOptions`], and may only differ from the defaults with the co-signature of the mint
+    /// authority of the SPL Token Mint or the admin of the program. Confidential Wrapped Tokens are 1:1 equivalents
+    /// of the SPL Token.
+    pub fn initialize(ctx: Context<Initialize>, options: InitializeOptions) -> Result<()> {
+        // Wrappers created before the Wrapper Config existed already own the mint
+        require_keys_eq!(
+            *ctx.accounts.confidential_mint.owner,
+            System::id(),
+            CtwError::MintAlreadyWrapped
+        );
+
+        check_mint_extensions(&ctx.accounts.token_mint.to_account_info())?;
+
         // Calculate space for the new mint with extensions
         let space = ExtensionType::try_calculate_account_len::<MintWithExtensions>(&[
             ExtensionType::ConfidentialTransferMint,
-        ])
-        .unwrap();
-        let rent = Rent::get()?.minimum_balance(space);
+        ])?;
 
         // Create the account for the new mint with extensions
-        anchor_lang::system_program::create_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::CreateAccount {
-                    from: ctx.accounts.payer.to_account_info(),
-                    to: ctx.accounts.confidential_mint.to_account_info(),
-                },
-                &[&[
-                    ctx.accounts.token_mint.key().as_ref(),
-                    MINT_SEED.as_ref(),
-                    &[ctx.bumps.confidential_mint],
-                ]],
-            ),
-            rent,
-            space as u64,
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.confidential_mint,
+            &ctx.accounts.system_program.to_account_info(),
+            space,
             ctx.accounts.token_extensions_program.key,
+            &[
+                ctx.accounts.token_mint.key().as_ref(),
+                MINT_SEED.as_ref(),
+                variant_seed(options.variant).as_slice(),
+                &[ctx.bumps.confidential_mint],
+            ],
         )?;
 
+        // Unless another authority is given, the wrapper authority manages the confidential transfer extension
+        let confidential_transfer_authority = options
+            .confidential_transfer_authority
+            .unwrap_or(ctx.accounts.wrapper_authority.key());
+        let auditor_elgamal_pubkey = options
+            .auditor_elgamal_pubkey
+            .filter(|auditor_elgamal_pubkey| auditor_elgamal_pubkey != &[0; 32]);
+
+        // Whoever initializes the wrapper first may not choose who can decrypt its confidential transfers
+        let cosigner_policy = ctx
+            .accounts
+            .auditor_authority
+            .as_ref()
+            .map(|auditor_authority| {
+                AuditorPolicy::of(
+                    auditor_authority.key,
+                    ctx.accounts.token_mint.mint_authority.into(),
+                    &ctx.accounts.global_config,
+                )
+            })
+            .unwrap_or_default();
+        let auditor_policy = if auditor_elgamal_pubkey.is_some()
+            || confidential_transfer_authority != ctx.accounts.wrapper_authority.key()
+        {
+            require!(
+                cosigner_policy != AuditorPolicy::None,
+                CtwError::AuditorNotAuthorized
+            );
+            cosigner_policy
+        } else {
+            AuditorPolicy::None
+        };
+
+        // Nor who may hold the wrapped tokens
+        if !options.auto_approve_new_accounts {
+            require!(
+                cosigner_policy != AuditorPolicy::None,
+                CtwError::ApprovalNotAuthorized
+            );
+        }
+
+        // Nor who administers the wrapper
+        let admin = match options.admin {
+            Some(admin) => {
+                require!(
+                    cosigner_policy != AuditorPolicy::None,
+                    CtwError::AdminNotAuthorized
+                );
+                admin
+            }
+            None => ctx
+                .accounts
+                .global_config
+                .default_wrapper_admin_for(ctx.accounts.token_mint.mint_authority.into()),
+        };
+
         // Initialize the confidential transfer extension
         anchor_lang::solana_program::program::invoke(
             &initialize_confidential_transfer(
                 &ctx.accounts.token_extensions_program.key(),
                 &ctx.accounts.confidential_mint.key(),
-                Some(ctx.accounts.program_authority.key()),
-                true, // By default we do not require approval of new token accounts
-                Some(
-                    spl_token_2022::solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey(
-                        auditor_pubkey,
-                    ),
-                ),
+                Some(confidential_transfer_authority),
+                options.auto_approve_new_accounts,
+                auditor_elgamal_pubkey.map(ElGamalPubkey),
             )?,
             &[
                 ctx.accounts.token_extensions_program.to_account_info(),
@@ -143,12 +762,14 @@ pub mod ctw_token {
             ],
         )?;
 
-        let freeze_authority = if let COption::Some(fa) = ctx.accounts.token_mint.freeze_authority {
-            Some(fa)
+        // The wrapper authority holds the freeze authority on behalf of the SPL Token Mint's freeze authority,
+        // so that it can be kept in sync through [`sync_freeze_authority`]
+        let freeze_authority = if let COption::Some(_) = ctx.accounts.token_mint.freeze_authority {
+            Some(ctx.accounts.wrapper_authority.key())
         } else {
             None
         };
- 
+
         // Initialize the new mint
         initialize_mint2(
             CpiContext::new_with_signer(
@@ -159,13 +780,88 @@ pub mod ctw_token {
                 &[&[
                     ctx.accounts.token_mint.key().as_ref(),
                     MINT_SEED.as_ref(),
+                    variant_seed(options.variant).as_slice(),
                     &[ctx.bumps.confidential_mint],
                 ]],
             ),
             ctx.accounts.token_mint.decimals,
-            &ctx.accounts.program_authority.key(),
+            &ctx.accounts.wrapper_authority.key(),
             freeze_authority.as_ref(),
         )?;
+
+        // The Associated Token Account of the wrapper authority is the vault itself,
+        // so the fee vault is a token account at a PDA of the SPL Token Mint
+        let fee_vault_space = get_token_account_len(&ctx.accounts.token_mint.to_account_info())?;
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.system_program.to_account_info(),
+            fee_vault_space,
+            ctx.accounts.token_program.key,
+            &[
+                ctx.accounts.token_mint.key().as_ref(),
+                FEE_VAULT_SEED.as_ref(),
+                variant_seed(options.variant).as_slice(),
+                &[ctx.bumps.fee_vault],
+            ],
+        )?;
+
+        initialize_account3(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: ctx.accounts.fee_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                authority: ctx.accounts.wrapper_authority.to_account_info(),
+            },
+        ))?;
+
+        // Record the wrapper so it can be discovered from a single account
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        wrapper_config.token_mint = ctx.accounts.token_mint.key();
+        wrapper_config.token_program = *ctx.accounts.token_mint.to_account_info().owner;
+        wrapper_config.confidential_mint = ctx.accounts.confidential_mint.key();
+        wrapper_config.token_vault = ctx.accounts.token_vault.key();
+        wrapper_config.fee_vault = ctx.accounts.fee_vault.key();
+        wrapper_config.admin = admin;
+        wrapper_config.confidential_transfer_authority = confidential_transfer_authority;
+        wrapper_config.auto_approve_new_accounts = options.auto_approve_new_accounts;
+        wrapper_config.auditor_elgamal_pubkey = auditor_elgamal_pubkey.unwrap_or_default();
+        wrapper_config.auditor_rotations = 0;
+        wrapper_config.auditor_history = Default::default();
+        wrapper_config.bump = ctx.bumps.wrapper_config;
+        wrapper_config.confidential_mint_bump = ctx.bumps.confidential_mint;
+        wrapper_config.authority_bump = ctx.bumps.wrapper_authority;
+        wrapper_config.created_slot = Clock::get()?.slot;
+        wrapper_config.total_wrapped = 0;
+        wrapper_config.total_unwrapped = 0;
+        wrapper_config.paused = ctx.accounts.global_config.default_paused;
+        wrapper_config.wrap_fee_bps = 0;
+        wrapper_config.unwrap_fee_bps = 0;
+        wrapper_config.total_fees = 0;
+        wrapper_config.max_supply = None;
+        wrapper_config.max_wrap_per_tx = None;
+        wrapper_config.min_wrap_amount = 0;
+        wrapper_config.unwrap_limit = None;
+        wrapper_config.unwrap_window_slots = 0;
+        wrapper_config.unwrap_outflow = 0;
+        wrapper_config.unwrap_outflow_slot = 0;
+        wrapper_config.previous_unwrap_outflow = 0;
+        wrapper_config.previous_unwrap_outflow_slot = 0;
+        wrapper_config.treasury = None;
+        wrapper_config.auditor_policy = auditor_policy;
+        wrapper_config.variant = options.variant;
+        wrapper_config.paused_by_global_authority = false;
+
+        emit!(WrapperInitialized {
+            token_mint: wrapper_config.token_mint,
+            token_program: wrapper_config.token_program,
+            confidential_mint: wrapper_config.confidential_mint,
+            token_vault: wrapper_config.token_vault,
+            variant: wrapper_config.variant,
+            payer: ctx.accounts.payer.key(),
+            slot: wrapper_config.created_slot,
+        });
+
         Ok(())
     }
 
@@ -175,61 +871,48 @@ pub mod ctw_token {
     ///
     /// The integrator is responsible for passing in a TokenAccount for the `confidential_token_account` param
     /// that has already been initialized and for which the [`ConfigureAccount`] as well as, if necessary,
-    /// the [`ApproveAccount`] instructions have been executed.
+    /// the [`ApproveAccount`] instructions have been executed. If the wrapper requires approval of new accounts,
+    /// the admin approves them through [`approve_account`].
     ///
     /// After this instruction is called, the integrator is then free to call [`ApplyPendingBalance`]
     /// in order to roll the token amount into the available balance of the Confidential Token Account.
-    pub fn wrap(ctx: Context<Wrap>, amount: u64) -> Result<()> {
-        // Transfer tokens from the source to the program's vault
-        transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.token_account.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
-                    to: ctx.accounts.token_vault.to_account_info(),
-                    authority: ctx.accounts.authority.to_account_info(),
-                },
-            ),
-            amount,
-            ctx.accounts.token_mint.decimals,
-        )?;
-
-        // Mint equivalent amount of tokens to the confidential wrapper token account
-        mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_extensions_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.confidential_mint.to_account_info(),
-                    to: ctx.accounts.confidential_token_account.to_account_info(),
-                    authority: ctx.accounts.program_authority.to_account_info(),
-                },
-                &[&[AUTHORITY_SEED.as_ref(), &[ctx.bumps.program_authority]]],
-            ),
-            amount,
-        )?;
-
-        // Deposit the minted tokens into the confidential balance of the account
-        // OBS: This will still require integrations to call [`ApplyPendingBalance`] afterwards.
-        invoke(
-            &deposit(
-                &ctx.accounts.token_extensions_program.key(),
-                &ctx.accounts.confidential_token_account.key(),
-                &ctx.accounts.confidential_mint.key(),
-                amount,
-                ctx.accounts.confidential_mint.decimals,
-                &ctx.accounts.authority.key(),
-                &[],
-            )
-            .unwrap(),
-            &[
-                ctx.accounts.confidential_token_account.to_account_info(),
-                ctx.accounts.confidential_mint.to_account_info(),
-                ctx.accounts.authority.to_account_info(),
-            ],
-        )?;
+    ///
+    /// If the SPL Token Mint charges a transfer fee, only the amount actually received by the vault is minted.
+    ///
+    /// The wrap fee of the wrapper is deducted from the amount received by the vault and moved into the fee vault,
+    /// only the remainder is minted. If the SPL Token Mint charges a transfer fee, moving the fee is charged one too,
+    /// and only what the fee vault received counts towards the total fees of the wrapper.
+    ///
+    /// Frozen SPL Token Accounts cannot be wrapped from.
+    ///
+    /// The amount must be within the per-transaction limits of the wrapper, and the supply of the
+    /// Confidential Wrapped Token Mint may not exceed its maximum supply once minted.
+    ///
+    /// Fails with [`CtwError::Paused`] while either the wrapper or every wrapper is paused through [`set_paused`].
+    ///
+    /// If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer into the vault
+    /// and for the transfer of the fee into the fee vault must be passed in as remaining accounts.
+    pub fn wrap<'info>(ctx: Context<'_, '_, '_, 'info, Wrap<'info>>, amount: u64) -> Result<()> {
+        process_wrap(ctx, amount, true)
+    }
 
-        Ok(())
+    /// Wrap the given token amount of an SPL Token into the Confidential Token Account of another owner, such as
+    /// a company paying out its employees.
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`wrap`], the minted tokens are left in the public balance of the recipient's account, as depositing
+    /// them into its confidential balance requires the signature of its owner, who may [`Deposit`] them later on.
+    /// The recipient's account therefore only needs to exist, not to be configured for confidential transfers,
+    /// unless the wrapper requires new accounts to be approved, in which case it fails with
+    /// [`CtwError::AccountNotApproved`] until the account is configured and approved.
+    ///
+    /// The fees, limits and pause of the wrapper apply as they do to [`wrap`].
+    ///
+    /// If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer into the vault
+    /// and for the transfer of the fee into the fee vault must be passed in as remaining accounts.
+    pub fn wrap_to<'info>(ctx: Context<'_, '_, '_, 'info, Wrap<'info>>, amount: u64) -> Result<()> {
+        process_wrap(ctx, amount, false)
     }
 
     /// Unwrap the given token amount of a Confidential Wrapped Token back into it's corresponding
@@ -240,7 +923,40 @@ pub mod ctw_token {
     /// The integrator is responsible for assuring that the user has enough non-confidential
     /// balance in order to unwrap and redeem for the underlying token.
     /// This can be achieved by having the [`Withdraw`] instruction being successfully executed beforehand.
-    pub fn unwrap(ctx: Context<Unwrap>, amount: u64) -> Result<()> {
+    ///
+    /// If the SPL Token Mint charges a transfer fee, it is withheld from the amount received by the user.
+    ///
+    /// The unwrap fee of the wrapper is deducted from the amount sent to the user and moved into the fee vault.
+    /// If the SPL Token Mint charges a transfer fee, moving the fee is charged one too, and only what the fee vault
+    /// received counts towards the total fees of the wrapper.
+    ///
+    /// Frozen SPL Token Accounts cannot be unwrapped into.
+    ///
+    /// The amount, including the fee, counts towards the unwrap limit of the wrapper set through [`set_unwrap_limit`].
+    ///
+    /// Fails with [`CtwError::Paused`] while either the wrapper or every wrapper is paused through [`set_paused`].
+    ///
+    /// If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer out of the vault
+    /// and for the transfer of the fee into the fee vault must be passed in as remaining accounts.
+    pub fn unwrap<'info>(ctx: Context<'_, '_, '_, 'info, Unwrap<'info>>, amount: u64) -> Result<()> {
+        require!(
+            !ctx.accounts.global_config.paused && !ctx.accounts.wrapper_config.paused,
+            CtwError::Paused
+        );
+        require_gt!(amount, 0, CtwError::ZeroAmount);
+        require!(
+            !ctx.accounts.token_account.is_frozen(),
+            CtwError::TokenAccountFrozen
+        );
+        require_gte!(
+            ctx.accounts.confidential_token_account.amount,
+            amount,
+            CtwError::InsufficientPublicBalance
+        );
+        ctx.accounts
+            .wrapper_config
+            .record_unwrap(amount, Clock::get()?.slot)?;
+
         // Burn the desired amount of tokens from the user's confidential token account
         invoke(
             &burn(
@@ -250,8 +966,7 @@ pub mod ctw_token {
                 &ctx.accounts.authority.key(),
                 &[],
                 amount,
-            )
-            .unwrap(),
+            )?,
             &[
                 ctx.accounts.confidential_token_account.to_account_info(),
                 ctx.accounts.confidential_mint.to_account_info(),
@@ -259,111 +974,2112 @@ pub mod ctw_token {
             ],
         )?;
 
+        let fee = calculate_fee(amount, ctx.accounts.wrapper_config.unwrap_fee_bps)?;
+        let unwrapped_amount = amount.checked_sub(fee).ok_or(CtwError::MathOverflow)?;
+
         // Transfer tokens from the program's vault to the destination account
-        transfer_checked(
+        transfer_checked_with_fee(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 TransferChecked {
                     from: ctx.accounts.token_vault.to_account_info(),
                     mint: ctx.accounts.token_mint.to_account_info(),
                     to: ctx.accounts.token_account.to_account_info(),
-                    authority: ctx.accounts.program_authority.to_account_info(),
+                    authority: ctx.accounts.wrapper_authority.to_account_info(),
                 },
-                &[&[AUTHORITY_SEED.as_ref(), &[ctx.bumps.program_authority]]],
-            ),
-            amount,
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            unwrapped_amount,
             ctx.accounts.token_mint.decimals,
         )?;
 
+        // Move the unwrap fee from the vault into the fee vault
+        let fee_vault_amount_before = ctx.accounts.fee_vault.amount;
+        if fee > 0 {
+            transfer_checked_with_fee(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.token_vault.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: ctx.accounts.fee_vault.to_account_info(),
+                        authority: ctx.accounts.wrapper_authority.to_account_info(),
+                    },
+                    &[&[
+                        ctx.accounts.token_mint.key().as_ref(),
+                        AUTHORITY_SEED.as_ref(),
+                        variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                        &[ctx.bumps.wrapper_authority],
+                    ]],
+                )
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                fee,
+                ctx.accounts.token_mint.decimals,
+            )?;
+        }
+
+        // Moving the fee may itself be charged a transfer fee, so only what the fee vault received is collected
+        ctx.accounts.fee_vault.reload()?;
+        let collected_fee = ctx
+            .accounts
+            .fee_vault
+            .amount
+            .checked_sub(fee_vault_amount_before)
+            .ok_or(CtwError::MathOverflow)?;
+
+        ctx.accounts.token_vault.reload()?;
+        ctx.accounts.confidential_mint.reload()?;
+        check_backing(&ctx.accounts.token_vault, &ctx.accounts.confidential_mint)?;
+
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        wrapper_config.total_unwrapped = wrapper_config
+            .total_unwrapped
+            .checked_add(amount)
+            .ok_or(CtwError::MathOverflow)?;
+        wrapper_config.total_fees = wrapper_config
+            .total_fees
+            .checked_add(collected_fee)
+            .ok_or(CtwError::MathOverflow)?;
+
+        emit!(Unwrapped {
+            token_mint: ctx.accounts.token_mint.key(),
+            confidential_mint: ctx.accounts.confidential_mint.key(),
+            owner: ctx.accounts.authority.key(),
+            amount,
+            fee,
+            vault_balance: ctx.accounts.token_vault.amount,
+            slot: Clock::get()?.slot,
+        });
+
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    /// The SPL Token Mint for which we want to create a Confidential Transfers Mint Wrapper.
-    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// Verify that the supply of a Confidential Wrapped Token is fully backed by the SPL Tokens held in the vault.
+    ///
+    /// # Notes
+    ///
+    /// This permissionless instruction does not modify any account, it logs the supply and the vault balance
+    /// and fails if the vault holds less than the supply, so it can be called by anyone, such as a keeper.
+    pub fn verify_backing(ctx: Context<VerifyBacking>) -> Result<()> {
+        msg!(
+            "Confidential Wrapped Token supply: {}, vault balance: {}",
+            ctx.accounts.confidential_mint.supply,
+            ctx.accounts.token_vault.amount
+        );
+
+        check_backing(&ctx.accounts.token_vault, &ctx.accounts.confidential_mint)
+    }
+
+    /// Update whether new CT-enabled Token Accounts must be approved and the auditor of a Confidential Wrapped Token Mint.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program, or the mint authority of the SPL Token Mint, may call this
+    /// instruction, and only if the wrapper authority is the confidential transfer authority of the Confidential
+    /// Wrapped Token Mint.
+    ///
+    /// A new auditor is recorded in the [`AuditorPolicy`] of the wrapper along with who chose it, the admin of the
+    /// wrapper having itself been chosen by the mint authority of the SPL Token Mint or the admin of the program.
+    pub fn update_confidential_mint(
+        ctx: Context<UpdateConfidentialMint>,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<[u8; 32]>,
+    ) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.wrapper_config.confidential_transfer_authority,
+            ctx.accounts.wrapper_authority.key(),
+            CtwError::ConfidentialTransferAuthorityNotProgram
+        );
+
+        invoke_signed(
+            &update_confidential_transfer(
+                &ctx.accounts.token_extensions_program.key(),
+                &ctx.accounts.confidential_mint.key(),
+                &ctx.accounts.wrapper_authority.key(),
+                auto_approve_new_accounts,
+                auditor_elgamal_pubkey.map(ElGamalPubkey),
+            )?,
+            &[
+                ctx.accounts.confidential_mint.to_account_info(),
+                ctx.accounts.wrapper_authority.to_account_info(),
+            ],
+            &[&[
+                ctx.accounts.token_mint.key().as_ref(),
+                AUTHORITY_SEED.as_ref(),
+                variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                &[ctx.bumps.wrapper_authority],
+            ]],
+        )?;
+
+        let auditor_policy = check_auditor_authority(
+            &ctx.accounts.wrapper_config,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.global_config,
+            ctx.accounts.admin.key,
+            auditor_elgamal_pubkey.unwrap_or_default(),
+        )?;
+
+        let slot = Clock::get()?.slot;
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        wrapper_config.auto_approve_new_accounts = auto_approve_new_accounts;
+        wrapper_config.set_auditor(auditor_elgamal_pubkey.unwrap_or_default(), slot)?;
+        wrapper_config.auditor_policy = auditor_policy;
+
+        emit!(ConfidentialMintUpdated {
+            token_mint: wrapper_config.token_mint,
+            confidential_mint: wrapper_config.confidential_mint,
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey: auditor_elgamal_pubkey.unwrap_or_default(),
+            admin: ctx.accounts.admin.key(),
+            slot,
+        });
+
+        Ok(())
+    }
+
+    /// Rotate the auditor of a Confidential Wrapped Token Mint, keeping whether new CT-enabled Token Accounts
+    /// must be approved as is.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program, or the mint authority of the SPL Token Mint, may call this
+    /// instruction, and only if the wrapper authority is the confidential transfer authority of the Confidential
+    /// Wrapped Token Mint.
+    ///
+    /// A new auditor is recorded in the [`AuditorPolicy`] of the wrapper along with who chose it, the admin of the
+    /// wrapper having itself been chosen by the mint authority of the SPL Token Mint or the admin of the program.
+    ///
+    /// The previous auditor is recorded in the history of the wrapper.
+    pub fn rotate_auditor(
+        ctx: Context<UpdateConfidentialMint>,
+        auditor_elgamal_pubkey: [u8; 32],
+    ) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.wrapper_config.confidential_transfer_authority,
+            ctx.accounts.wrapper_authority.key(),
+            CtwError::ConfidentialTransferAuthorityNotProgram
+        );
+
+        invoke_signed(
+            &update_confidential_transfer(
+                &ctx.accounts.token_extensions_program.key(),
+                &ctx.accounts.confidential_mint.key(),
+                &ctx.accounts.wrapper_authority.key(),
+                ctx.accounts.wrapper_config.auto_approve_new_accounts,
+                Some(ElGamalPubkey(auditor_elgamal_pubkey)),
+            )?,
+            &[
+                ctx.accounts.confidential_mint.to_account_info(),
+                ctx.accounts.wrapper_authority.to_account_info(),
+            ],
+            &[&[
+                ctx.accounts.token_mint.key().as_ref(),
+                AUTHORITY_SEED.as_ref(),
+                variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                &[ctx.bumps.wrapper_authority],
+            ]],
+        )?;
+
+        let auditor_policy = check_auditor_authority(
+            &ctx.accounts.wrapper_config,
+            &ctx.accounts.token_mint,
+            &ctx.accounts.global_config,
+            ctx.accounts.admin.key,
+            auditor_elgamal_pubkey,
+        )?;
+
+        let slot = Clock::get()?.slot;
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        let previous_auditor_elgamal_pubkey = wrapper_config.auditor_elgamal_pubkey;
+        wrapper_config.set_auditor(auditor_elgamal_pubkey, slot)?;
+        wrapper_config.auditor_policy = auditor_policy;
+
+        emit!(AuditorRotated {
+            token_mint: wrapper_config.token_mint,
+            confidential_mint: wrapper_config.confidential_mint,
+            previous_auditor_elgamal_pubkey,
+            auditor_elgamal_pubkey,
+            slot,
+        });
+
+        Ok(())
+    }
+
+    /// Approve a CT-enabled Token Account of a Confidential Wrapped Token Mint which requires approval of new accounts.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program may call this instruction, and only if the wrapper authority
+    /// is the confidential transfer authority of the Confidential Wrapped Token Mint.
+    ///
+    /// The Confidential Token Account must have been configured with [`ConfigureAccount`] beforehand.
+    pub fn approve_account(ctx: Context<ApproveAccount>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.wrapper_config.confidential_transfer_authority,
+            ctx.accounts.wrapper_authority.key(),
+            CtwError::ConfidentialTransferAuthorityNotProgram
+        );
+
+        invoke_signed(
+            &spl_token_2022::extension::confidential_transfer::instruction::approve_account(
+                &ctx.accounts.token_extensions_program.key(),
+                &ctx.accounts.confidential_token_account.key(),
+                &ctx.accounts.confidential_mint.key(),
+                &ctx.accounts.wrapper_authority.key(),
+                &[],
+            )?,
+            &[
+                ctx.accounts.confidential_token_account.to_account_info(),
+                ctx.accounts.confidential_mint.to_account_info(),
+                ctx.accounts.wrapper_authority.to_account_info(),
+            ],
+            &[&[
+                ctx.accounts.token_mint.key().as_ref(),
+                AUTHORITY_SEED.as_ref(),
+                variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                &[ctx.bumps.wrapper_authority],
+            ]],
+        )?;
+
+        emit!(AccountApproved {
+            token_mint: ctx.accounts.token_mint.key(),
+            confidential_mint: ctx.accounts.confidential_mint.key(),
+            confidential_token_account: ctx.accounts.confidential_token_account.key(),
+            admin: ctx.accounts.admin.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Mirror the current freeze authority of the SPL Token Mint onto the Confidential Wrapped Token Mint.
+    ///
+    /// # Notes
+    ///
+    /// This instruction is permissionless. While the SPL Token Mint has a freeze authority, the wrapper authority
+    /// holds the freeze authority of the Confidential Wrapped Token Mint on its behalf, and once the SPL Token Mint's
+    /// freeze authority is revoked, the wrapper authority revokes its own through `SetAuthority`.
+    ///
+    /// Wrappers whose Confidential Wrapped Token Mint has a freeze authority other than the wrapper authority
+    /// can only be synced while it matches the SPL Token Mint's freeze authority. Those whose Confidential Wrapped
+    /// Token Mint's freeze authority was revoked while the SPL Token Mint still has one fail with
+    /// [`CtwError::FreezeAuthorityRevoked`], as `SetAuthority` cannot restore a revoked authority.
+    pub fn sync_freeze_authority(ctx: Context<SyncFreezeAuthority>) -> Result<()> {
+        let wrapper_authority = ctx.accounts.wrapper_authority.key();
+        let token_freeze_authority = Option::<Pubkey>::from(ctx.accounts.token_mint.freeze_authority);
+        let confidential_freeze_authority =
+            Option::<Pubkey>::from(ctx.accounts.confidential_mint.freeze_authority);
+        let expected_freeze_authority = token_freeze_authority.map(|_| wrapper_authority);
+
+        if confidential_freeze_authority == expected_freeze_authority
+            || confidential_freeze_authority == token_freeze_authority
+        {
+            msg!("The freeze authority is already in sync");
+            return Ok(());
+        }
+
+        // A revoked freeze authority cannot be set again, so the SPL Token Mint's can no longer be mirrored
+        require!(
+            confidential_freeze_authority.is_some(),
+            CtwError::FreezeAuthorityRevoked
+        );
+        require_keys_eq!(
+            confidential_freeze_authority.unwrap_or_default(),
+            wrapper_authority,
+            CtwError::FreezeAuthorityNotProgram
+        );
+
+        anchor_spl::token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_extensions_program.to_account_info(),
+                anchor_spl::token_interface::SetAuthority {
+                    current_authority: ctx.accounts.wrapper_authority.to_account_info(),
+                    account_or_mint: ctx.accounts.confidential_mint.to_account_info(),
+                },
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            ),
+            AuthorityType::FreezeAccount,
+            expected_freeze_authority,
+        )?;
+
+        emit!(FreezeAuthoritySynced {
+            token_mint: ctx.accounts.token_mint.key(),
+            confidential_mint: ctx.accounts.confidential_mint.key(),
+            freeze_authority: expected_freeze_authority,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Freeze a Confidential Token Account of a Confidential Wrapped Token Mint.
+    ///
+    /// # Notes
+    ///
+    /// Only the current freeze authority of the SPL Token Mint may call this instruction.
+    pub fn freeze_account(ctx: Context<FreezeConfidentialAccount>) -> Result<()> {
+        anchor_spl::token_interface::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_extensions_program.to_account_info(),
+            anchor_spl::token_interface::FreezeAccount {
+                account: ctx.accounts.confidential_token_account.to_account_info(),
+                mint: ctx.accounts.confidential_mint.to_account_info(),
+                authority: ctx.accounts.wrapper_authority.to_account_info(),
+            },
+            &[&[
+                ctx.accounts.token_mint.key().as_ref(),
+                AUTHORITY_SEED.as_ref(),
+                variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                &[ctx.bumps.wrapper_authority],
+            ]],
+        ))?;
+
+        emit!(AccountFreezeUpdated {
+            token_mint: ctx.accounts.token_mint.key(),
+            confidential_mint: ctx.accounts.confidential_mint.key(),
+            confidential_token_account: ctx.accounts.confidential_token_account.key(),
+            frozen: true,
+            freeze_authority: ctx.accounts.freeze_authority.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Thaw a frozen Confidential Token Account of a Confidential Wrapped Token Mint.
+    ///
+    /// # Notes
+    ///
+    /// Only the current freeze authority of the SPL Token Mint may call this instruction.
+    pub fn thaw_account(ctx: Context<FreezeConfidentialAccount>) -> Result<()> {
+        anchor_spl::token_interface::thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_extensions_program.to_account_info(),
+            anchor_spl::token_interface::ThawAccount {
+                account: ctx.accounts.confidential_token_account.to_account_info(),
+                mint: ctx.accounts.confidential_mint.to_account_info(),
+                authority: ctx.accounts.wrapper_authority.to_account_info(),
+            },
+            &[&[
+                ctx.accounts.token_mint.key().as_ref(),
+                AUTHORITY_SEED.as_ref(),
+                variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                &[ctx.bumps.wrapper_authority],
+            ]],
+        ))?;
+
+        emit!(AccountFreezeUpdated {
+            token_mint: ctx.accounts.token_mint.key(),
+            confidential_mint: ctx.accounts.confidential_mint.key(),
+            confidential_token_account: ctx.accounts.confidential_token_account.key(),
+            frozen: false,
+            freeze_authority: ctx.accounts.freeze_authority.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Initialize the global state of the Confidential Wrapped Token Program.
+    ///
+    /// # Notes
+    ///
+    /// Only the upgrade authority of the program may call this instruction, and only once.
+    pub fn initialize_global(
+        ctx: Context<InitializeGlobal>,
+        admin: Pubkey,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        let global_config = &mut ctx.accounts.global_config;
+        global_config.admin = admin;
+        global_config.pending_admin = None;
+        global_config.guardian = guardian;
+        global_config.paused = false;
+        global_config.default_wrapper_admin = None;
+        global_config.default_paused = false;
+        global_config.bump = ctx.bumps.global_config;
+
+        emit!(GlobalInitialized {
+            admin,
+            guardian,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Pause or unpause wrapping and unwrapping, for the given wrapper or, if none is given, for every wrapper.
+    ///
+    /// # Notes
+    ///
+    /// The admin and the guardian of the program may pause and unpause every wrapper as well as any single wrapper,
+    /// while the admin of a wrapper may only pause and unpause its own wrapper, unless it was paused by the admin
+    /// or the guardian of the program.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let global_config = &mut ctx.accounts.global_config;
+        let is_global_authority =
+            authority == global_config.admin || authority == global_config.guardian;
+
+        let token_mint = match ctx.accounts.wrapper_config.as_mut() {
+            Some(wrapper_config) => {
+                require!(
+                    is_global_authority || global_config.is_wrapper_admin(wrapper_config, &authority),
+                    CtwError::Unauthorized
+                );
+                // A pause by the admin or the guardian of the program may not be lifted by the wrapper admin
+                require!(
+                    paused || is_global_authority || !wrapper_config.paused_by_global_authority,
+                    CtwError::Unauthorized
+                );
+                wrapper_config.paused = paused;
+                wrapper_config.paused_by_global_authority =
+                    paused && (is_global_authority || wrapper_config.paused_by_global_authority);
+                Some(wrapper_config.token_mint)
+            }
+            None => {
+                require!(is_global_authority, CtwError::Unauthorized);
+                global_config.paused = paused;
+                None
+            }
+        };
+
+        emit!(PauseUpdated {
+            token_mint,
+            paused,
+            authority,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Propose a new admin of the program, which only becomes the admin once it calls [`accept_admin`].
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the program may call this instruction, proposing another admin replaces the pending one.
+    pub fn transfer_admin(ctx: Context<UpdateGlobalConfig>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.global_config.pending_admin = Some(new_admin);
+
+        emit!(AdminProposed {
+            admin: ctx.accounts.admin.key(),
+            pending_admin: new_admin,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Accept the admin role of the program proposed through [`transfer_admin`].
+    ///
+    /// # Notes
+    ///
+    /// Only the pending admin of the program may call this instruction.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let global_config = &mut ctx.accounts.global_config;
+        let previous_admin = global_config.admin;
+        global_config.admin = ctx.accounts.pending_admin.key();
+        global_config.pending_admin = None;
+
+        emit!(AdminTransferred {
+            previous_admin,
+            admin: global_config.admin,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Replace the guardian of the program.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the program may call this instruction.
+    pub fn set_guardian(ctx: Context<UpdateGlobalConfig>, guardian: Pubkey) -> Result<()> {
+        let previous_guardian = ctx.accounts.global_config.guardian;
+        ctx.accounts.global_config.guardian = guardian;
+
+        emit!(GuardianUpdated {
+            previous_guardian,
+            guardian,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Update the policy applied to new wrappers, which does not affect existing wrappers.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the program may call this instruction.
+    pub fn set_default_policy(
+        ctx: Context<UpdateGlobalConfig>,
+        default_wrapper_admin: Option<Pubkey>,
+        default_paused: bool,
+    ) -> Result<()> {
+        let global_config = &mut ctx.accounts.global_config;
+        global_config.default_wrapper_admin = default_wrapper_admin;
+        global_config.default_paused = default_paused;
+
+        emit!(DefaultPolicyUpdated {
+            default_wrapper_admin,
+            default_paused,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Set the fees, in basis points, deducted from the SPL Tokens being wrapped and unwrapped.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program may call this instruction, and neither fee may exceed
+    /// [`MAX_FEE_BPS`]. As the payer of [`initialize`] never becomes the admin of the wrapper, whoever initialized
+    /// it cannot set its fees.
+    pub fn set_fees(
+        ctx: Context<UpdateWrapperConfig>,
+        wrap_fee_bps: u16,
+        unwrap_fee_bps: u16,
+    ) -> Result<()> {
+        require_gte!(MAX_FEE_BPS, wrap_fee_bps, CtwError::FeeTooHigh);
+        require_gte!(MAX_FEE_BPS, unwrap_fee_bps, CtwError::FeeTooHigh);
+
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        wrapper_config.wrap_fee_bps = wrap_fee_bps;
+        wrapper_config.unwrap_fee_bps = unwrap_fee_bps;
+
+        emit!(FeesUpdated {
+            token_mint: ctx.accounts.token_mint.key(),
+            wrap_fee_bps,
+            unwrap_fee_bps,
+            admin: ctx.accounts.admin.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Set the maximum supply of the Confidential Wrapped Token Mint and the limits of the amount wrapped
+    /// in a single instruction, where `None` means unlimited.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program may call this instruction. Lowering the maximum supply
+    /// below the current supply only prevents further wrapping, unwrapping is unaffected.
+    pub fn set_limits(
+        ctx: Context<UpdateWrapperConfig>,
+        max_supply: Option<u64>,
+        max_wrap_per_tx: Option<u64>,
+        min_wrap_amount: u64,
+    ) -> Result<()> {
+        if let Some(max_wrap_per_tx) = max_wrap_per_tx {
+            require_gte!(max_wrap_per_tx, min_wrap_amount, CtwError::InvalidLimits);
+        }
+
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        wrapper_config.max_supply = max_supply;
+        wrapper_config.max_wrap_per_tx = max_wrap_per_tx;
+        wrapper_config.min_wrap_amount = min_wrap_amount;
+
+        emit!(LimitsUpdated {
+            token_mint: ctx.accounts.token_mint.key(),
+            max_supply,
+            max_wrap_per_tx,
+            min_wrap_amount,
+            admin: ctx.accounts.admin.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Set the maximum amount which may be unwrapped within any window of the given number of slots,
+    /// where `None` means unlimited.
+    ///
+    /// # Notes
+    ///
+    /// The admin of the wrapper or of the program may set any unwrap limit, while the guardian of the program
+    /// may only tighten it, by lowering the limit or lengthening the window. The amount already unwrapped within
+    /// the current window still counts towards the new limit.
+    pub fn set_unwrap_limit(
+        ctx: Context<SetUnwrapLimit>,
+        unwrap_limit: Option<u64>,
+        unwrap_window_slots: u64,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let global_config = &ctx.accounts.global_config;
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+
+        if !global_config.is_wrapper_admin(wrapper_config, &authority) {
+            require!(
+                authority == global_config.guardian
+                    && wrapper_config.is_tighter_unwrap_limit(unwrap_limit, unwrap_window_slots),
+                CtwError::Unauthorized
+            );
+        }
+        if unwrap_limit.is_some() {
+            require_gt!(unwrap_window_slots, 0, CtwError::InvalidUnwrapWindow);
+        }
+
+        let slot = Clock::get()?.slot;
+        wrapper_config.set_unwrap_limit(unwrap_limit, unwrap_window_slots, slot);
+
+        emit!(UnwrapLimitUpdated {
+            token_mint: ctx.accounts.token_mint.key(),
+            unwrap_limit,
+            unwrap_window_slots,
+            authority,
+            slot,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw the given amount of collected fees from the fee vault into the given token account.
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program may call this instruction.
+    ///
+    /// If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts.
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFees<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        require_gt!(amount, 0, CtwError::ZeroAmount);
+
+        transfer_checked_with_fee(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.fee_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.wrapper_authority.to_account_info(),
+                },
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        emit!(FeesWithdrawn {
+            token_mint: ctx.accounts.token_mint.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Set the token account receiving the surplus of the vault swept through [`sweep_surplus`].
+    ///
+    /// # Notes
+    ///
+    /// Only the admin of the wrapper or of the program may call this instruction.
+    pub fn set_treasury(ctx: Context<UpdateWrapperConfig>, treasury: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.wrapper_config.treasury = treasury;
+
+        emit!(TreasuryUpdated {
+            token_mint: ctx.accounts.token_mint.key(),
+            treasury,
+            admin: ctx.accounts.admin.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Sweep the SPL Tokens held by the vault in excess of the supply of the Confidential Wrapped Token Mint,
+    /// such as tokens transferred directly into the vault, into the treasury of the wrapper.
+    ///
+    /// # Notes
+    ///
+    /// This instruction is permissionless, as the surplus may only be sent to the treasury set through [`set_treasury`].
+    ///
+    /// If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts.
+    pub fn sweep_surplus<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepSurplus<'info>>,
+    ) -> Result<()> {
+        let treasury = ctx
+            .accounts
+            .wrapper_config
+            .treasury
+            .ok_or(CtwError::TreasuryNotSet)?;
+        require_keys_eq!(
+            ctx.accounts.treasury.key(),
+            treasury,
+            CtwError::InvalidTreasury
+        );
+
+        let surplus = ctx
+            .accounts
+            .token_vault
+            .amount
+            .checked_sub(ctx.accounts.confidential_mint.supply)
+            .ok_or(CtwError::VaultInvariantViolated)?;
+        require_gt!(surplus, 0, CtwError::NoSurplus);
+
+        transfer_checked_with_fee(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.wrapper_authority.to_account_info(),
+                },
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            surplus,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        // A transfer hook must not be able to leave the vault short of the supply
+        ctx.accounts.token_vault.reload()?;
+        check_backing(&ctx.accounts.token_vault, &ctx.accounts.confidential_mint)?;
+
+        emit!(SurplusSwept {
+            token_mint: ctx.accounts.token_mint.key(),
+            treasury,
+            amount: surplus,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Close a wrapper whose Confidential Wrapped Token is no longer in circulation, refunding the rent of its
+    /// vault, fee vault and state to the given destination.
+    ///
+    /// # Notes
+    ///
+    /// Only the mint authority of the SPL Token Mint or the admin of the program may call this instruction, once the
+    /// supply of the Confidential Wrapped Token Mint is zero and both the vault and the fee vault are empty. Any
+    /// transfer fee withheld in either vault must be harvested beforehand.
+    ///
+    /// The Confidential Wrapped Token Mint cannot be closed and is left disabled, its mint authority being revoked,
+    /// so the variant cannot be wrapped again. As closing is permanent, the admin of the wrapper alone may not
+    /// close it.
+    pub fn close_wrapper(ctx: Context<CloseWrapper>) -> Result<()> {
+        require_eq!(
+            ctx.accounts.confidential_mint.supply,
+            0,
+            CtwError::SupplyNotZero
+        );
+        require!(
+            ctx.accounts.token_vault.amount == 0 && ctx.accounts.fee_vault.amount == 0,
+            CtwError::VaultNotEmpty
+        );
+
+        for vault in [&ctx.accounts.token_vault, &ctx.accounts.fee_vault] {
+            anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.wrapper_authority.to_account_info(),
+                },
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            ))?;
+        }
+
+        anchor_spl::token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_extensions_program.to_account_info(),
+                anchor_spl::token_interface::SetAuthority {
+                    current_authority: ctx.accounts.wrapper_authority.to_account_info(),
+                    account_or_mint: ctx.accounts.confidential_mint.to_account_info(),
+                },
+                &[&[
+                    ctx.accounts.token_mint.key().as_ref(),
+                    AUTHORITY_SEED.as_ref(),
+                    variant_seed(ctx.accounts.wrapper_config.variant).as_slice(),
+                    &[ctx.bumps.wrapper_authority],
+                ]],
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        emit!(WrapperClosed {
+            token_mint: ctx.accounts.token_mint.key(),
+            confidential_mint: ctx.accounts.confidential_mint.key(),
+            admin: ctx.accounts.admin.key(),
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Move a wrapper created under the authority formerly shared by every wrapper to its own wrapper authority.
+    ///
+    /// # Notes
+    ///
+    /// This instruction is permissionless. The wrapped SPL Tokens are moved to the vault of the wrapper authority
+    /// and the vault of the shared authority is closed to the admin of the wrapper, as whoever calls it did not pay
+    /// for that vault, then the wrapper authority takes over the fee vault as well as the mint, freeze and
+    /// confidential transfer authorities held by the shared authority.
+    ///
+    /// If the SPL Token Mint charges a transfer fee, the vault must hold enough surplus to cover the fee charged
+    /// on the move, and any transfer fee withheld in the vault must be harvested beforehand.
+    ///
+    /// If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts.
+    pub fn migrate_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAuthority<'info>>,
+    ) -> Result<()> {
+        let program_authority = ctx.accounts.program_authority.key();
+        let wrapper_authority = ctx.accounts.wrapper_authority.key();
+
+        let program_authority_seeds: &[&[&[u8]]] =
+            &[&[AUTHORITY_SEED.as_ref(), &[ctx.bumps.program_authority]]];
+
+        let amount = ctx.accounts.legacy_token_vault.amount;
+        if amount > 0 {
+            transfer_checked_with_fee(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.legacy_token_vault.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: ctx.accounts.token_vault.to_account_info(),
+                        authority: ctx.accounts.program_authority.to_account_info(),
+                    },
+                    program_authority_seeds,
+                )
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                amount,
+                ctx.accounts.token_mint.decimals,
+            )?;
+        }
+
+        anchor_spl::token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token_interface::CloseAccount {
+                account: ctx.accounts.legacy_token_vault.to_account_info(),
+                destination: ctx.accounts.admin.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+            program_authority_seeds,
+        ))?;
+
+        anchor_spl::token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_interface::SetAuthority {
+                    current_authority: ctx.accounts.program_authority.to_account_info(),
+                    account_or_mint: ctx.accounts.fee_vault.to_account_info(),
+                },
+                program_authority_seeds,
+            ),
+            AuthorityType::AccountOwner,
+            Some(wrapper_authority),
+        )?;
+
+        let mut authority_types = vec![AuthorityType::MintTokens];
+        if ctx.accounts.confidential_mint.freeze_authority == COption::Some(program_authority) {
+            authority_types.push(AuthorityType::FreezeAccount);
+        }
+        if ctx.accounts.wrapper_config.confidential_transfer_authority == program_authority {
+            authority_types.push(AuthorityType::ConfidentialTransferMint);
+        }
+
+        for authority_type in authority_types {
+            anchor_spl::token_interface::set_authority(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_extensions_program.to_account_info(),
+                    anchor_spl::token_interface::SetAuthority {
+                        current_authority: ctx.accounts.program_authority.to_account_info(),
+                        account_or_mint: ctx.accounts.confidential_mint.to_account_info(),
+                    },
+                    program_authority_seeds,
+                ),
+                authority_type,
+                Some(wrapper_authority),
+            )?;
+        }
+
+        // Anything short of the supply would have been lost to a transfer fee or a transfer hook
+        ctx.accounts.token_vault.reload()?;
+        check_backing(&ctx.accounts.token_vault, &ctx.accounts.confidential_mint)?;
+
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        if wrapper_config.confidential_transfer_authority == program_authority {
+            wrapper_config.confidential_transfer_authority = wrapper_authority;
+        }
+        wrapper_config.token_vault = ctx.accounts.token_vault.key();
+        wrapper_config.authority_bump = ctx.bumps.wrapper_authority;
+
+        emit!(AuthorityMigrated {
+            token_mint: ctx.accounts.token_mint.key(),
+            wrapper_authority,
+            token_vault: ctx.accounts.token_vault.key(),
+            amount,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Create the missing Wrapper Config of a wrapper initialized before the Wrapper Config existed, reconstructing
+    /// it from the Confidential Wrapped Token Mint and the vault.
+    ///
+    /// # Notes
+    ///
+    /// This instruction is permissionless. The Confidential Wrapped Token Mint must still be minted by the authority
+    /// formerly shared by every wrapper and have the confidential transfer extension, otherwise it fails with
+    /// [`CtwError::NotLegacyWrapper`], and the vault must hold at least its supply. Such wrappers are always
+    /// the first variant of their SPL Token Mint.
+    ///
+    /// The confidential transfer authority, whether new accounts are approved and the auditor are read from the
+    /// extension, and the supply is recorded as the total wrapped so far. As the payer may be anyone, the admin is
+    /// chosen as for [`initialize`] without an admin: the default wrapper admin of the program, else the mint
+    /// authority of the SPL Token Mint, else the admin of the program. Like new wrappers, the adopted wrapper
+    /// starts paused if the [`GlobalConfig`] says so.
+    ///
+    /// A fee vault owned by the shared authority is created alongside, so that the wrapper can then be moved to its
+    /// own wrapper authority through [`migrate_authority`], without which it cannot be used.
+    pub fn adopt_legacy_wrapper(ctx: Context<AdoptLegacyWrapper>) -> Result<()> {
+        check_backing(&ctx.accounts.token_vault, &ctx.accounts.confidential_mint)?;
+
+        let (confidential_transfer_authority, auto_approve_new_accounts, auditor_elgamal_pubkey) = {
+            let confidential_mint = ctx.accounts.confidential_mint.to_account_info();
+            let mint_data = confidential_mint.try_borrow_data()?;
+            let mint = StateWithExtensions::<MintWithExtensions>::unpack(&mint_data)?;
+            let confidential_transfer_mint = mint
+                .get_extension::<ConfidentialTransferMint>()
+                .map_err(|_| error!(CtwError::NotLegacyWrapper))?;
+            (
+                Option::<Pubkey>::from(confidential_transfer_mint.authority).unwrap_or_default(),
+                bool::from(confidential_transfer_mint.auto_approve_new_accounts),
+                Option::<ElGamalPubkey>::from(confidential_transfer_mint.auditor_elgamal_pubkey)
+                    .map(|auditor_elgamal_pubkey| auditor_elgamal_pubkey.0)
+                    .unwrap_or_default(),
+            )
+        };
+
+        // The fee vault is owned by the shared authority until the wrapper is migrated along with its vault
+        let fee_vault_space = get_token_account_len(&ctx.accounts.token_mint.to_account_info())?;
+        create_pda_account(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.system_program.to_account_info(),
+            fee_vault_space,
+            ctx.accounts.token_program.key,
+            &[
+                ctx.accounts.token_mint.key().as_ref(),
+                FEE_VAULT_SEED.as_ref(),
+                &[ctx.bumps.fee_vault],
+            ],
+        )?;
+
+        initialize_account3(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: ctx.accounts.fee_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                authority: ctx.accounts.program_authority.to_account_info(),
+            },
+        ))?;
+
+        let wrapper_config = &mut ctx.accounts.wrapper_config;
+        wrapper_config.token_mint = ctx.accounts.token_mint.key();
+        wrapper_config.token_program = *ctx.accounts.token_mint.to_account_info().owner;
+        wrapper_config.confidential_mint = ctx.accounts.confidential_mint.key();
+        wrapper_config.token_vault = ctx.accounts.token_vault.key();
+        wrapper_config.fee_vault = ctx.accounts.fee_vault.key();
+        wrapper_config.admin = ctx
+            .accounts
+            .global_config
+            .default_wrapper_admin_for(ctx.accounts.token_mint.mint_authority.into());
+        wrapper_config.confidential_transfer_authority = confidential_transfer_authority;
+        wrapper_config.auto_approve_new_accounts = auto_approve_new_accounts;
+        wrapper_config.auditor_elgamal_pubkey = auditor_elgamal_pubkey;
+        wrapper_config.auditor_rotations = 0;
+        wrapper_config.auditor_history = Default::default();
+        wrapper_config.bump = ctx.bumps.wrapper_config;
+        wrapper_config.confidential_mint_bump = ctx.bumps.confidential_mint;
+        wrapper_config.authority_bump = ctx.bumps.program_authority;
+        wrapper_config.created_slot = Clock::get()?.slot;
+        wrapper_config.total_wrapped = ctx.accounts.confidential_mint.supply;
+        wrapper_config.total_unwrapped = 0;
+        wrapper_config.paused = ctx.accounts.global_config.default_paused;
+        wrapper_config.wrap_fee_bps = 0;
+        wrapper_config.unwrap_fee_bps = 0;
+        wrapper_config.total_fees = 0;
+        wrapper_config.max_supply = None;
+        wrapper_config.max_wrap_per_tx = None;
+        wrapper_config.min_wrap_amount = 0;
+        wrapper_config.unwrap_limit = None;
+        wrapper_config.unwrap_window_slots = 0;
+        wrapper_config.unwrap_outflow = 0;
+        wrapper_config.unwrap_outflow_slot = 0;
+        wrapper_config.previous_unwrap_outflow = 0;
+        wrapper_config.previous_unwrap_outflow_slot = 0;
+        wrapper_config.treasury = None;
+        wrapper_config.auditor_policy = AuditorPolicy::None;
+        wrapper_config.variant = 0;
+        wrapper_config.paused_by_global_authority = false;
+
+        emit!(LegacyWrapperAdopted {
+            token_mint: wrapper_config.token_mint,
+            confidential_mint: wrapper_config.confidential_mint,
+            token_vault: wrapper_config.token_vault,
+            supply: ctx.accounts.confidential_mint.supply,
+            auditor_elgamal_pubkey,
+            freeze_authority: ctx.accounts.confidential_mint.freeze_authority.into(),
+            slot: wrapper_config.created_slot,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(options: InitializeOptions)]
+pub struct Initialize<'info> {
+    /// The SPL Token Mint for which we want to create a Confidential Transfers Mint Wrapper.
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(options.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The SPL Token Extensions Mint.
+    /// CHECK: Seeds are checked.
+    pub confidential_mint: AccountInfo<'info>,
+
+    #[account(
+        init,
+        space = 8 + WrapperConfig::INIT_SPACE,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(options.variant).as_slice()
+        ],
+        bump,
+        payer = payer,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(options.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    /// The token vault, which anyone may have created beforehand as it is an Associated Token Account.
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_mint,
+        associated_token::authority = wrapper_authority,
+        associated_token::token_program = token_program,
+        payer = payer,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            FEE_VAULT_SEED.as_ref(),
+            variant_seed(options.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The vault collecting the wrap and unwrap fees.
+    /// CHECK: Seeds are checked.
+    pub fee_vault: AccountInfo<'info>,
+
+    /// The fee and rent payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The mint authority of the SPL Token Mint or the admin of the program, required to choose an auditor,
+    /// a confidential transfer authority other than the wrapper authority or the admin, or to require approval.
+    pub auditor_authority: Option<Signer<'info>>,
+
+    /// The Token Program which owns the SPL Token Mint, either the SPL Token Program or the Token Extensions Program.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The Associated Token Program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The Token Extensions Program.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+
+    /// The System Program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Wrap<'info> {
+    /// The mint of the token being wrapped.
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ], 
+        bump,
+    )]
+    /// The mint of the token being wrapped.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+        has_one = token_vault,
+        has_one = fee_vault,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The vault collecting the wrap and unwrap fees.
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub confidential_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    /// The authority of the source token account.
+    pub authority: Signer<'info>,
+
+    /// The fee and rent payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The Token Interface.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The Token Interface.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+}
+
+#[derive(Accounts)]
+pub struct Unwrap<'info> {
+    /// The mint of the token being wrapped.
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The mint of the token being wrapped.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+        has_one = token_vault,
+        has_one = fee_vault,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The vault collecting the wrap and unwrap fees.
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub confidential_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    /// The authority of the source token account.
+    pub authority: Signer<'info>,
+
+    /// The fee and rent payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The Token Interface.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// The Token Interface.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfidentialMint<'info> {
+    /// The mint of the token being wrapped.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+    )]
+    /// The mint of the Confidential Wrapped Token.
+    /// CHECK: Seeds are checked.
+    pub confidential_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    #[account(
+        constraint = global_config.is_wrapper_admin(&wrapper_config, admin.key)
+            || token_mint.mint_authority == COption::Some(admin.key())
+            @ CtwError::Unauthorized
+    )]
+    /// The admin of the wrapper or of the program, or the mint authority of the SPL Token Mint.
+    pub admin: Signer<'info>,
+
+    /// The Token Extensions Program.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAccount<'info> {
+    /// The mint of the token being wrapped.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+    )]
+    /// The mint of the Confidential Wrapped Token.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        token::mint = confidential_mint,
+    )]
+    /// The Confidential Token Account to approve.
+    pub confidential_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    #[account(
+        constraint = global_config.is_wrapper_admin(&wrapper_config, admin.key)
+            @ CtwError::Unauthorized
+    )]
+    /// The admin of the wrapper or of the program.
+    pub admin: Signer<'info>,
+
+    /// The Token Extensions Program.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyBacking<'info> {
+    /// The mint of the token being wrapped.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token vault.
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of the Confidential Wrapped Token.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+        has_one = token_vault,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct SyncFreezeAuthority<'info> {
+    /// The mint of the token being wrapped.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+    )]
+    /// The mint of the Confidential Wrapped Token.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    /// The Token Extensions Program.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeConfidentialAccount<'info> {
+    /// The mint of the token being wrapped.
+    #[account(
+        constraint = token_mint.freeze_authority == COption::Some(freeze_authority.key())
+            @ CtwError::FreezeAuthorityMismatch
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+    )]
+    /// The mint of the Confidential Wrapped Token.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        mut,
+        token::mint = confidential_mint,
+    )]
+    /// The Confidential Token Account to freeze or thaw.
+    pub confidential_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    /// The freeze authority of the SPL Token Mint.
+    pub freeze_authority: Signer<'info>,
+
+    /// The Token Extensions Program.
+    pub token_extensions_program: Program<'info, TokenExtensions>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGlobal<'info> {
+    #[account(
+        init,
+        space = 8 + GlobalConfig::INIT_SPACE,
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump,
+        payer = payer,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+    )]
+    /// The Confidential Wrapped Token Program.
+    pub program: Program<'info, crate::program::CtwToken>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ CtwError::NotUpgradeAuthority
+    )]
+    /// The program data of the Confidential Wrapped Token Program.
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The upgrade authority of the Confidential Wrapped Token Program.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The fee and rent payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The System Program.
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(mut)]
+    /// The state of the Confidential Wrapped Token to pause or unpause, every wrapper if none is given.
+    pub wrapper_config: Option<Box<Account<'info, WrapperConfig>>>,
+
+    /// The admin or guardian of the program, or the admin of the wrapper.
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGlobalConfig<'info> {
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+        has_one = admin @ CtwError::Unauthorized,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// The admin of the program.
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+        constraint = global_config.pending_admin == Some(pending_admin.key()) @ CtwError::Unauthorized,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// The pending admin of the program.
+    pub pending_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateWrapperConfig<'info> {
+    /// The mint of the token being wrapped.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        constraint = global_config.is_wrapper_admin(&wrapper_config, admin.key)
+            @ CtwError::Unauthorized
+    )]
+    /// The admin of the wrapper or of the program.
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetUnwrapLimit<'info> {
+    /// The mint of the token being wrapped.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// The admin of the wrapper or of the program, or the guardian of the program.
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    /// The mint of the token being wrapped.
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = fee_vault,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
 
     #[account(
         mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The vault collecting the wrap and unwrap fees.
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The token account receiving the fees.
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
         seeds = [
             token_mint.key().as_ref(),
-            MINT_SEED.as_ref()
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
         ],
         bump
     )]
-    /// The SPL Token Extensions Mint.
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
     /// CHECK: Seeds are checked.
-    pub confidential_mint: AccountInfo<'info>,
+    pub wrapper_authority: AccountInfo<'info>,
+
+    #[account(
+        constraint = global_config.is_wrapper_admin(&wrapper_config, admin.key)
+            @ CtwError::Unauthorized
+    )]
+    /// The admin of the wrapper or of the program.
+    pub admin: Signer<'info>,
+
+    /// The Token Program which owns the SPL Token Mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SweepSurplus<'info> {
+    /// The mint of the token being wrapped.
+    #[account(
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         seeds = [
-            AUTHORITY_SEED.as_ref()
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+    )]
+    /// The mint of the Confidential Wrapped Token.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+        has_one = token_vault,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The vault holding the wrapped SPL Tokens.
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The treasury of the wrapper, receiving the surplus.
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
         ],
         bump
     )]
-    /// The authority of the Confidential Wrapper Token Program.
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
     /// CHECK: Seeds are checked.
-    pub program_authority: AccountInfo<'info>,
+    pub wrapper_authority: AccountInfo<'info>,
 
-    /// The token vault.
+    /// The Token Program which owns the SPL Token Mint.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CloseWrapper<'info> {
+    /// The mint of the token being wrapped.
     #[account(
-        init,
-        associated_token::mint = token_mint,
-        associated_token::authority = program_authority,
-        payer = payer,
+        mint::token_program = token_program
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+    )]
+    /// The mint of the Confidential Wrapped Token.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+        has_one = token_vault,
+        has_one = fee_vault,
+        close = destination,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
     )]
+    /// The vault holding the wrapped SPL Tokens.
     pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// The fee and rent payer.
+    #[account(
+        mut,
+        token::authority = wrapper_authority,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The vault collecting the wrap and unwrap fees.
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
+
+    #[account(
+        constraint = AuditorPolicy::of(admin.key, token_mint.mint_authority.into(), &global_config)
+            != AuditorPolicy::None
+            @ CtwError::Unauthorized
+    )]
+    /// The mint authority of the SPL Token Mint or the admin of the program.
+    pub admin: Signer<'info>,
+
     #[account(mut)]
-    pub payer: Signer<'info>,
+    /// The account receiving the rent of the closed accounts.
+    pub destination: SystemAccount<'info>,
 
-    /// The Token Program.
+    /// The Token Program which owns the SPL Token Mint.
     pub token_program: Interface<'info, TokenInterface>,
 
-    /// The Associated Token Program.
-    pub associated_token_program: Program<'info, AssociatedToken>,
-
     /// The Token Extensions Program.
     pub token_extensions_program: Program<'info, TokenExtensions>,
-
-    /// The System Program.
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Wrap<'info> {
+pub struct MigrateAuthority<'info> {
     /// The mint of the token being wrapped.
+    #[account(
+        mint::token_program = token_program
+    )]
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
-        token::authority = authority,
-        token::mint = token_mint
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.confidential_mint_bump,
+        constraint = confidential_mint.mint_authority == COption::Some(program_authority.key())
+            @ CtwError::AuthorityAlreadyMigrated,
     )]
-    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The mint of the Confidential Wrapped Token, still minted by the shared authority.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
-        token::authority = program_authority,
-        token::mint = token_mint
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump = wrapper_config.bump,
+        has_one = token_mint,
+        has_one = confidential_mint,
+        has_one = fee_vault,
     )]
-    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
 
     #[account(
         mut,
-        seeds = [
-            token_mint.key().as_ref(),
-            MINT_SEED.as_ref()
-        ], 
-        bump,
+        address = wrapper_config.token_vault,
+        token::mint = token_mint,
+        token::token_program = token_program
     )]
-    /// The mint of the token being wrapped.
-    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// The vault of the shared authority, holding the wrapped SPL Tokens until the migration.
+    pub legacy_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mut)]
-    pub confidential_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The vault of the wrapper authority, which anyone may have created beforehand as it is an Associated Token Account.
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_mint,
+        associated_token::authority = wrapper_authority,
+        associated_token::token_program = token_program,
+        payer = payer,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    /// The vault collecting the wrap and unwrap fees.
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds = [
@@ -371,56 +3087,109 @@ pub struct Wrap<'info> {
         ],
         bump
     )]
-    /// The authority of the Confidential Wrapper Token Program.
+    /// The authority formerly shared by every wrapper.
     /// CHECK: Seeds are checked.
     pub program_authority: AccountInfo<'info>,
 
-    /// The authority of the source token account.
-    pub authority: Signer<'info>,
+    #[account(
+        seeds = [
+            token_mint.key().as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(wrapper_config.variant).as_slice()
+        ],
+        bump
+    )]
+    /// The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.
+    /// CHECK: Seeds are checked.
+    pub wrapper_authority: AccountInfo<'info>,
 
-    /// The fee and rent payer.
+    #[account(
+        mut,
+        address = wrapper_config.admin @ CtwError::Unauthorized
+    )]
+    /// The admin of the wrapper, receiving the rent of the vault of the shared authority.
+    /// CHECK: Checked against the admin of the wrapper.
+    pub admin: AccountInfo<'info>,
+
+    /// The rent payer of the vault of the wrapper authority, if it has yet to be created.
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// The Token Interface.
+    /// The Token Program which owns the SPL Token Mint.
     pub token_program: Interface<'info, TokenInterface>,
 
-    /// The Token Interface.
+    /// The Associated Token Program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The Token Extensions Program.
     pub token_extensions_program: Program<'info, TokenExtensions>,
+
+    /// The System Program.
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct Unwrap<'info> {
+pub struct AdoptLegacyWrapper<'info> {
     /// The mint of the token being wrapped.
+    #[account(
+        mint::token_program = token_program
+    )]
     pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
-        mut,
-        token::authority = authority,
-        token::mint = token_mint
+        seeds = [
+            token_mint.key().as_ref(),
+            MINT_SEED.as_ref()
+        ],
+        bump,
+        constraint = confidential_mint.mint_authority == COption::Some(program_authority.key())
+            @ CtwError::NotLegacyWrapper,
+        constraint = confidential_mint.decimals == token_mint.decimals @ CtwError::NotLegacyWrapper,
     )]
-    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The mint of the Confidential Wrapped Token, minted by the shared authority.
+    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
-        mut,
-        token::authority = program_authority,
-        token::mint = token_mint
+        init,
+        space = 8 + WrapperConfig::INIT_SPACE,
+        seeds = [
+            token_mint.key().as_ref(),
+            WRAPPER_SEED.as_ref()
+        ],
+        bump,
+        payer = payer,
+    )]
+    /// The state of the Confidential Wrapped Token.
+    pub wrapper_config: Box<Account<'info, WrapperConfig>>,
+
+    #[account(
+        seeds = [
+            GLOBAL_SEED.as_ref()
+        ],
+        bump = global_config.bump,
+    )]
+    /// The global state of the Confidential Wrapped Token Program.
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        associated_token::mint = token_mint,
+        associated_token::authority = program_authority,
+        associated_token::token_program = token_program,
     )]
+    /// The vault of the shared authority, holding the wrapped SPL Tokens.
     pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         seeds = [
             token_mint.key().as_ref(),
-            MINT_SEED.as_ref()
+            FEE_VAULT_SEED.as_ref()
         ],
         bump
     )]
-    /// The mint of the token being wrapped.
-    pub confidential_mint: Box<InterfaceAccount<'info, Mint>>,
-
-    #[account(mut)]
-    pub confidential_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The vault collecting the wrap and unwrap fees.
+    /// CHECK: Seeds are checked.
+    pub fee_vault: AccountInfo<'info>,
 
     #[account(
         seeds = [
@@ -428,30 +3197,187 @@ pub struct Unwrap<'info> {
         ],
         bump
     )]
-    /// The authority of the Confidential Wrapper Token Program.
+    /// The authority formerly shared by every wrapper.
     /// CHECK: Seeds are checked.
     pub program_authority: AccountInfo<'info>,
 
-    /// The authority of the source token account.
-    pub authority: Signer<'info>,
-
-    /// The fee and rent payer.
+    /// The rent payer.
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// The Token Interface.
+    /// The Token Program which owns the SPL Token Mint.
     pub token_program: Interface<'info, TokenInterface>,
 
-    /// The Token Interface.
-    pub token_extensions_program: Program<'info, TokenExtensions>,
+    /// The System Program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Derives the Confidential Wrapped Token Mint of the given variant of the wrapper of an SPL Token Mint.
+#[cfg(feature = "client")]
+pub fn derive_confidential_mint(token_mint: &Pubkey, variant: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            token_mint.as_ref(),
+            MINT_SEED.as_ref(),
+            variant_seed(variant).as_slice(),
+        ],
+        &crate::id(),
+    )
 }
 
 #[cfg(feature = "client")]
-pub fn derive_confidential_mint(token_mint: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[token_mint.as_ref(), MINT_SEED.as_ref()], &crate::id())
+pub fn derive_wrapper_config(token_mint: &Pubkey, variant: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            token_mint.as_ref(),
+            WRAPPER_SEED.as_ref(),
+            variant_seed(variant).as_slice(),
+        ],
+        &crate::id(),
+    )
 }
 
+/// Derives the authority shared by the wrappers created before each wrapper had its own authority, which only
+/// remains relevant to migrate them with `migrate_authority`.
 #[cfg(feature = "client")]
 pub fn derive_authority() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[AUTHORITY_SEED.as_ref()], &crate::id())
 }
+
+/// Derives the authority of the given variant of the wrapper of an SPL Token Mint, which mints its Confidential
+/// Wrapped Token and owns its vaults.
+#[cfg(feature = "client")]
+pub fn derive_wrapper_authority(token_mint: &Pubkey, variant: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            token_mint.as_ref(),
+            AUTHORITY_SEED.as_ref(),
+            variant_seed(variant).as_slice(),
+        ],
+        &crate::id(),
+    )
+}
+
+#[cfg(feature = "client")]
+pub fn derive_global_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GLOBAL_SEED.as_ref()], &crate::id())
+}
+
+#[cfg(feature = "client")]
+pub fn derive_fee_vault(token_mint: &Pubkey, variant: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            token_mint.as_ref(),
+            FEE_VAULT_SEED.as_ref(),
+            variant_seed(variant).as_slice(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// Derives the program data account of the Confidential Wrapped Token Program.
+#[cfg(feature = "client")]
+pub fn derive_program_data() -> Pubkey {
+    Pubkey::find_program_address(
+        &[crate::id().as_ref()],
+        &solana_program::bpf_loader_upgradeable::id(),
+    )
+    .0
+}
+
+/// Derives the vault of the given variant of the wrapper of an SPL Token Mint, where `token_program` is the program
+/// which owns the mint.
+#[cfg(feature = "client")]
+pub fn derive_token_vault(token_mint: &Pubkey, variant: u16, token_program: &Pubkey) -> Pubkey {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        &wrapper_authority,
+        token_mint,
+        token_program,
+    )
+}
+
+/// Derives the vault of the given SPL Token Mint for a wrapper created before each wrapper had its own authority.
+#[cfg(feature = "client")]
+pub fn derive_legacy_token_vault(token_mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    let (program_authority, _) = derive_authority();
+    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+        &program_authority,
+        token_mint,
+        token_program,
+    )
+}
+
+/// Resolves the extra accounts required by the transfer hook of the given SPL Token Mint for the given transfer,
+/// if it has one. These must be passed in as remaining accounts to the `wrap` and `unwrap` instructions, along with
+/// the ones of the transfer of the fee into the fee vault, merged through [`merge_account_metas`].
+#[cfg(feature = "client")]
+pub async fn resolve_transfer_hook_accounts<F, Fut>(
+    fetch_account_data_fn: F,
+    token_program: &Pubkey,
+    source: &Pubkey,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> std::result::Result<Vec<AccountMeta>, spl_token_2022::offchain::AccountFetchError>
+where
+    F: Fn(Pubkey) -> Fut,
+    Fut: std::future::Future<Output = spl_token_2022::offchain::AccountDataResult>,
+{
+    if token_program != &spl_token_2022::id() {
+        return Ok(vec![]);
+    }
+
+    let mint_data = fetch_account_data_fn(*token_mint)
+        .await?
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let transfer_hook_program_id = {
+        let mint = StateWithExtensions::<MintWithExtensions>::unpack(&mint_data)?;
+        match transfer_hook::get_program_id(&mint) {
+            Some(transfer_hook_program_id) => transfer_hook_program_id,
+            None => return Ok(vec![]),
+        }
+    };
+
+    // The extra accounts are resolved for the `Execute` instruction of the hook, as the Token Extensions Program
+    // does, since accounts derived from seeds are derived from the program id of the hook
+    let mut instruction = execute(
+        &transfer_hook_program_id,
+        source,
+        token_mint,
+        destination,
+        authority,
+        &get_extra_account_metas_address(token_mint, &transfer_hook_program_id),
+        amount,
+    );
+    spl_transfer_hook_interface::offchain::resolve_extra_account_metas(
+        &mut instruction,
+        fetch_account_data_fn,
+        token_mint,
+        &transfer_hook_program_id,
+    )
+    .await?;
+
+    // The first five accounts are the ones of the `Execute` instruction itself
+    Ok(instruction.accounts.split_off(5))
+}
+
+/// Merges the extra accounts resolved for several transfers of the same instruction, keeping each account once
+/// with the privileges it requires in any of them.
+#[cfg(feature = "client")]
+pub fn merge_account_metas(
+    account_metas: impl IntoIterator<Item = Vec<AccountMeta>>,
+) -> Vec<AccountMeta> {
+    let mut merged: Vec<AccountMeta> = vec![];
+    for account_meta in account_metas.into_iter().flatten() {
+        match merged.iter_mut().find(|m| m.pubkey == account_meta.pubkey) {
+            Some(m) => {
+                m.is_signer |= account_meta.is_signer;
+                m.is_writable |= account_meta.is_writable;
+            }
+            None => merged.push(account_meta),
+        }
+    }
+    merged
+}
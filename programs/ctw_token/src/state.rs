@@ -0,0 +1,302 @@
+use anchor_lang::prelude::*;
+
+/// The number of previous auditors kept in the history of a [`WrapperConfig`].
+pub const AUDITOR_HISTORY_LEN: usize = 4;
+
+/// The maximum fee, in basis points, which may be charged when wrapping or unwrapping.
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Calculate the fee, rounded down, charged on the given amount at the given rate in basis points.
+pub fn calculate_fee(amount: u64, fee_bps: u16) -> Result<u64> {
+    u64::try_from(amount as u128 * fee_bps as u128 / 10_000)
+        .map_err(|_| error!(crate::CtwError::MathOverflow))
+}
+
+/// The per-mint state of a Confidential Wrapped Token.
+///
+/// This account is created alongside the Confidential Wrapped Token Mint and the vault, and
+/// records which SPL Token Mint is being wrapped by which Confidential Wrapped Token Mint so that
+/// integrators only need to fetch a single account instead of re-deriving every PDA.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct WrapperConfig {
+    /// The SPL Token Mint being wrapped.
+    pub token_mint: Pubkey,
+    /// The Token Program which owns the SPL Token Mint.
+    pub token_program: Pubkey,
+    /// The Confidential Wrapped Token Mint.
+    pub confidential_mint: Pubkey,
+    /// The vault holding the wrapped SPL Tokens.
+    pub token_vault: Pubkey,
+    /// The vault collecting the wrap and unwrap fees.
+    pub fee_vault: Pubkey,
+    /// The admin of the wrapper, allowed to update the confidential transfer extension.
+    pub admin: Pubkey,
+    /// The confidential transfer authority of the Confidential Wrapped Token Mint.
+    pub confidential_transfer_authority: Pubkey,
+    /// Whether new CT-enabled Token Accounts are approved without the confidential transfer authority.
+    pub auto_approve_new_accounts: bool,
+    /// The auditor ElGamal public key of the Confidential Wrapped Token Mint, all zeroes if there is none.
+    pub auditor_elgamal_pubkey: [u8; 32],
+    /// The number of times the auditor has been replaced.
+    pub auditor_rotations: u64,
+    /// The previous auditors, as a ring buffer indexed by the number of rotations.
+    pub auditor_history: [AuditorRecord; AUDITOR_HISTORY_LEN],
+    /// The bump of this account.
+    pub bump: u8,
+    /// The bump of the Confidential Wrapped Token Mint.
+    pub confidential_mint_bump: u8,
+    /// The bump of the wrapper authority.
+    pub authority_bump: u8,
+    /// The slot at which the wrapper was initialized.
+    pub created_slot: u64,
+    /// The total amount of SPL Tokens ever wrapped, excluding fees.
+    pub total_wrapped: u64,
+    /// The total amount of SPL Tokens ever unwrapped, including fees.
+    pub total_unwrapped: u64,
+    /// Whether wrapping and unwrapping are paused for this wrapper.
+    pub paused: bool,
+    /// The fee, in basis points, deducted from the SPL Tokens being wrapped.
+    pub wrap_fee_bps: u16,
+    /// The fee, in basis points, deducted from the SPL Tokens being unwrapped.
+    pub unwrap_fee_bps: u16,
+    /// The total amount of SPL Tokens ever received by the fee vault as fees, net of any transfer fee
+    /// charged on moving them into it.
+    pub total_fees: u64,
+    /// The maximum supply of the Confidential Wrapped Token Mint, unlimited if there is none.
+    pub max_supply: Option<u64>,
+    /// The maximum amount which may be wrapped in a single instruction, unlimited if there is none.
+    pub max_wrap_per_tx: Option<u64>,
+    /// The minimum amount which may be wrapped in a single instruction.
+    pub min_wrap_amount: u64,
+    /// The maximum amount which may be unwrapped within any window of `unwrap_window_slots` slots,
+    /// unlimited if there is none.
+    pub unwrap_limit: Option<u64>,
+    /// The length, in slots, of the window over which the unwrap limit applies.
+    pub unwrap_window_slots: u64,
+    /// The amount unwrapped within the window of `unwrap_window_slots` slots containing `unwrap_outflow_slot`.
+    pub unwrap_outflow: u64,
+    /// The slot at which the unwrap outflow was last updated.
+    pub unwrap_outflow_slot: u64,
+    /// The amount unwrapped within the window before the one of the unwrap outflow.
+    pub previous_unwrap_outflow: u64,
+    /// The slot at which the previous unwrap outflow was last updated.
+    pub previous_unwrap_outflow_slot: u64,
+    /// The token account receiving the surplus of the vault, none if it has not been set.
+    pub treasury: Option<Pubkey>,
+    /// Who authorized the current auditor and confidential transfer authority.
+    pub auditor_policy: AuditorPolicy,
+    /// The variant distinguishing this wrapper from the other wrappers of the same SPL Token Mint.
+    pub variant: u16,
+    /// Whether the wrapper was paused by the admin or guardian of the program, who alone may then unpause it.
+    pub paused_by_global_authority: bool,
+}
+
+impl WrapperConfig {
+    /// Replace the auditor, keeping the previous one in the history.
+    pub fn set_auditor(&mut self, auditor_elgamal_pubkey: [u8; 32], slot: u64) -> Result<()> {
+        if auditor_elgamal_pubkey == self.auditor_elgamal_pubkey {
+            return Ok(());
+        }
+
+        let index = (self.auditor_rotations % AUDITOR_HISTORY_LEN as u64) as usize;
+        self.auditor_history[index] = AuditorRecord {
+            elgamal_pubkey: self.auditor_elgamal_pubkey,
+            replaced_slot: slot,
+        };
+        self.auditor_rotations = self
+            .auditor_rotations
+            .checked_add(1)
+            .ok_or(crate::CtwError::MathOverflow)?;
+        self.auditor_elgamal_pubkey = auditor_elgamal_pubkey;
+
+        Ok(())
+    }
+
+    /// The outflows of the window containing the given slot and of the window before it, along with the slots
+    /// at which they were last updated.
+    fn unwrap_outflows_at(&self, slot: u64) -> (u64, u64, u64, u64) {
+        let window = self.unwrap_window_slots.max(1);
+        if slot / window == self.unwrap_outflow_slot / window {
+            (
+                self.previous_unwrap_outflow,
+                self.previous_unwrap_outflow_slot,
+                self.unwrap_outflow,
+                self.unwrap_outflow_slot,
+            )
+        } else {
+            (self.unwrap_outflow, self.unwrap_outflow_slot, 0, slot)
+        }
+    }
+
+    /// The amount counting towards the unwrap limit at the given slot, which includes every unwrap within the
+    /// window of `unwrap_window_slots` slots ending at it.
+    pub fn unwrap_outflow_at(&self, slot: u64) -> u64 {
+        if self.unwrap_limit.is_none() {
+            return 0;
+        }
+        let (previous_outflow, previous_slot, outflow, _) = self.unwrap_outflows_at(slot);
+
+        if slot < previous_slot.saturating_add(self.unwrap_window_slots) {
+            outflow.saturating_add(previous_outflow)
+        } else {
+            outflow
+        }
+    }
+
+    /// The amount which may still be unwrapped at the given slot, unlimited if there is no unwrap limit.
+    pub fn remaining_unwrap_capacity(&self, slot: u64) -> Option<u64> {
+        self.unwrap_limit
+            .map(|unwrap_limit| unwrap_limit.saturating_sub(self.unwrap_outflow_at(slot)))
+    }
+
+    /// Record an unwrap of the given amount at the given slot, failing if it exceeds the remaining unwrap capacity.
+    pub fn record_unwrap(&mut self, amount: u64, slot: u64) -> Result<()> {
+        if let Some(remaining) = self.remaining_unwrap_capacity(slot) {
+            require_gte!(remaining, amount, crate::CtwError::UnwrapLimitExceeded);
+            let (previous_outflow, previous_slot, outflow, _) = self.unwrap_outflows_at(slot);
+            self.previous_unwrap_outflow = previous_outflow;
+            self.previous_unwrap_outflow_slot = previous_slot;
+            self.unwrap_outflow = outflow
+                .checked_add(amount)
+                .ok_or(crate::CtwError::MathOverflow)?;
+            self.unwrap_outflow_slot = slot;
+        }
+
+        Ok(())
+    }
+
+    /// Replace the unwrap limit, counting the outflow of the current window towards the new one.
+    pub fn set_unwrap_limit(
+        &mut self,
+        unwrap_limit: Option<u64>,
+        unwrap_window_slots: u64,
+        slot: u64,
+    ) {
+        self.unwrap_outflow = self.unwrap_outflow_at(slot);
+        self.unwrap_outflow_slot = slot;
+        self.previous_unwrap_outflow = 0;
+        self.previous_unwrap_outflow_slot = 0;
+        self.unwrap_limit = unwrap_limit;
+        self.unwrap_window_slots = unwrap_window_slots;
+    }
+
+    /// Whether the given unwrap limit is at most as permissive as the current one.
+    pub fn is_tighter_unwrap_limit(
+        &self,
+        unwrap_limit: Option<u64>,
+        unwrap_window_slots: u64,
+    ) -> bool {
+        match (self.unwrap_limit, unwrap_limit) {
+            (_, None) => self.unwrap_limit.is_none(),
+            (None, Some(_)) => true,
+            (Some(current), Some(new)) => {
+                new <= current && unwrap_window_slots >= self.unwrap_window_slots
+            }
+        }
+    }
+}
+
+/// The global state of the Confidential Wrapped Token Program.
+///
+/// This account is created once by the upgrade authority of the program and holds the roles
+/// which may act on every wrapper, such as pausing all of them at once, as well as the policy
+/// applied to new wrappers.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct GlobalConfig {
+    /// The admin of the program, allowed to act as the admin of any wrapper and to update this account.
+    pub admin: Pubkey,
+    /// The admin proposed through `transfer_admin`, which becomes the admin once it accepts.
+    pub pending_admin: Option<Pubkey>,
+    /// The guardian of the program, allowed to pause and unpause any wrapper.
+    pub guardian: Pubkey,
+    /// Whether wrapping and unwrapping are paused for every wrapper.
+    pub paused: bool,
+    /// The admin of new wrappers, the mint authority of the SPL Token Mint or else the admin of the program
+    /// if there is none.
+    pub default_wrapper_admin: Option<Pubkey>,
+    /// Whether new wrappers are paused until unpaused through `set_paused`.
+    pub default_paused: bool,
+    /// The bump of this account.
+    pub bump: u8,
+}
+
+impl GlobalConfig {
+    /// Whether the given key may act as the admin of the given wrapper.
+    pub fn is_wrapper_admin(&self, wrapper_config: &WrapperConfig, key: &Pubkey) -> bool {
+        *key == wrapper_config.admin || *key == self.admin
+    }
+
+    /// The admin of a new wrapper which was not given one: the default wrapper admin, else the given mint
+    /// authority of the SPL Token Mint, else the admin of the program.
+    pub fn default_wrapper_admin_for(&self, mint_authority: Option<Pubkey>) -> Pubkey {
+        self.default_wrapper_admin
+            .or(mint_authority)
+            .unwrap_or(self.admin)
+    }
+}
+
+/// A previous auditor of a Confidential Wrapped Token Mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct AuditorRecord {
+    /// The auditor ElGamal public key, all zeroes if there was none.
+    pub elgamal_pubkey: [u8; 32],
+    /// The slot at which the auditor was replaced.
+    pub replaced_slot: u64,
+}
+
+/// Who authorized the auditor of a Confidential Wrapped Token Mint.
+///
+/// As [`initialize`](crate::ctw_token::initialize) is permissionless, only the mint authority of the SPL Token Mint
+/// or the admin of the program may choose who can decrypt the confidential transfers of a new wrapper, which the
+/// admin of the wrapper, always chosen by either of them, may then replace.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum AuditorPolicy {
+    /// The wrapper has no auditor and the wrapper authority is its confidential transfer authority.
+    #[default]
+    None,
+    /// The mint authority of the SPL Token Mint co-signed.
+    MintAuthority,
+    /// The admin of the program co-signed.
+    GlobalAdmin,
+    /// The admin of the wrapper rotated it.
+    WrapperAdmin,
+}
+
+impl AuditorPolicy {
+    /// The policy under which the given key may choose the auditor of a wrapper of an SPL Token Mint
+    /// with the given mint authority, none if it may not.
+    pub fn of(key: &Pubkey, mint_authority: Option<Pubkey>, global_config: &GlobalConfig) -> Self {
+        if mint_authority == Some(*key) {
+            AuditorPolicy::MintAuthority
+        } else if *key == global_config.admin {
+            AuditorPolicy::GlobalAdmin
+        } else {
+            AuditorPolicy::None
+        }
+    }
+}
+
+/// The options of the confidential transfer extension of a new Confidential Wrapped Token Mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitializeOptions {
+    /// Whether new CT-enabled Token Accounts are approved without the confidential transfer authority.
+    pub auto_approve_new_accounts: bool,
+    /// The confidential transfer authority, defaults to the wrapper authority.
+    /// Any other authority must be co-signed by the mint authority of the SPL Token Mint or the admin of the program.
+    pub confidential_transfer_authority: Option<Pubkey>,
+    /// The auditor ElGamal public key, which must be co-signed by the mint authority of the SPL Token Mint
+    /// or the admin of the program.
+    /// `solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey` length is 32 but it doesn't impl Borsh.
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>,
+    /// The variant of the wrapper, so that an SPL Token Mint may have several wrappers with their own auditor
+    /// and approval policy, each with its own vaults. The first variant is `0`.
+    pub variant: u16,
+    /// The admin of the wrapper, which must be co-signed by the mint authority of the SPL Token Mint
+    /// or the admin of the program. Defaults to the default wrapper admin of the global config,
+    /// or else the mint authority of the SPL Token Mint, or else the admin of the program.
+    pub admin: Option<Pubkey>,
+}
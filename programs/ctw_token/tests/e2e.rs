@@ -1,9 +1,7 @@
 use {
-    anchor_lang::{InstructionData, ToAccountMetas},
+    anchor_lang::{AccountDeserialize, AccountSerialize, Event, InstructionData, ToAccountMetas},
     anchor_spl::{
-        associated_token::{
-            self, get_associated_token_address, get_associated_token_address_with_program_id,
-        },
+        associated_token::{self, get_associated_token_address_with_program_id},
         token::{
             spl_token::{
                 self,
@@ -23,6 +21,7 @@ use {
                     auth_encryption::AeKey,
                     elgamal::{ElGamalCiphertext, ElGamalKeypair},
                 },
+                zk_token_elgamal::pod::ElGamalPubkey,
                 zk_token_proof_instruction::{
                     verify_pubkey_validity, verify_withdraw, PubkeyValidityData, WithdrawData,
                 },
@@ -30,28 +29,92 @@ use {
         },
     },
     ctw_token::{
-        accounts::{Initialize, Unwrap, Wrap},
-        derive_authority, derive_confidential_mint,
+        accounts::{
+            AcceptAdmin, AdoptLegacyWrapper, ApproveAccount, CloseWrapper,
+            FreezeConfidentialAccount, Initialize, InitializeGlobal, MigrateAuthority, SetPaused,
+            SetUnwrapLimit, SweepSurplus, SyncFreezeAuthority, Unwrap, UpdateConfidentialMint,
+            UpdateGlobalConfig, UpdateWrapperConfig, VerifyBacking, WithdrawFees, Wrap,
+        },
+        derive_authority, derive_confidential_mint, derive_fee_vault, derive_global_config,
+        derive_legacy_token_vault, derive_program_data, derive_token_vault,
+        derive_wrapper_authority, derive_wrapper_config, merge_account_metas,
+        resolve_transfer_hook_accounts, AuditorPolicy, CtwError, CtwEvent, GlobalConfig,
+        InitializeOptions, LimitsUpdated, Wrapped, WrapperConfig, MAX_FEE_BPS,
     },
     solana_program::{
-        instruction::Instruction, native_token::sol_to_lamports, program_option::COption,
-        program_pack::Pack, pubkey::Pubkey, system_instruction::create_account, system_program,
+        bpf_loader_upgradeable,
+        instruction::{AccountMeta, Instruction},
+        native_token::sol_to_lamports,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey,
+        pubkey::Pubkey,
+        rent::Rent,
+        system_instruction::{create_account, transfer},
+        system_program, sysvar,
+    },
+    solana_program_test::{
+        find_file, processor, read_file, tokio, BanksClient, BanksClientError, ProgramTest,
+        ProgramTestContext,
+    },
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
     },
-    solana_program_test::{tokio, BanksClient, BanksClientError, ProgramTest, ProgramTestContext},
-    solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction},
     spl_associated_token_account::instruction::create_associated_token_account,
+    spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+    },
     spl_token_2022::{
+        error::TokenError,
         extension::{
-            confidential_transfer::ConfidentialTransferAccount, BaseStateWithExtensions,
-            ExtensionType, StateWithExtensions,
+            confidential_transfer::{ConfidentialTransferAccount, ConfidentialTransferMint},
+            default_account_state::instruction::{
+                initialize_default_account_state, update_default_account_state,
+            },
+            interest_bearing_mint,
+            transfer_fee::instruction::initialize_transfer_fee_config,
+            transfer_hook, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+            StateWithExtensionsMut,
         },
-        instruction::reallocate,
-        state::{Account, Mint},
+        instruction::{
+            freeze_account, initialize_mint2, initialize_mint_close_authority,
+            initialize_non_transferable_mint, initialize_permanent_delegate, mint_to, reallocate,
+            set_authority, thaw_account, transfer_checked, AuthorityType,
+        },
+        state::{Account, AccountState, Mint},
+    },
+    spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::initialize_extra_account_meta_list,
     },
     std::num::NonZeroI8,
 };
 
+const TRANSFER_HOOK_PROGRAM_ID: Pubkey = pubkey!("TokenHookExampLe8smaVNrxTBezWTRbEwxwb1Zykrb");
+
 pub async fn start_new_program_test() -> ProgramTestContext {
+    let (mut context, upgrade_authority) = start_new_program_test_without_global().await;
+
+    let admin = context.payer.pubkey();
+    initialize_global(
+        &mut context.banks_client,
+        &context.payer,
+        &upgrade_authority,
+        &admin,
+        &admin,
+    )
+    .await
+    .unwrap();
+
+    context
+}
+
+/// Starts a program test where the program is deployed through the upgradeable loader, as it is on a cluster,
+/// but its global state has yet to be initialized by the returned upgrade authority.
+pub async fn start_new_program_test_without_global() -> (ProgramTestContext, Keypair) {
     // Supress some of the logs
     solana_logger::setup_with_default(
         "solana_rbpf::vm=info,\
@@ -60,9 +123,21 @@ pub async fn start_new_program_test() -> ProgramTestContext {
               solana_program_test=info",
     );
 
-    let mut test = ProgramTest::new("ctw_token", ctw_token::id(), None);
+    let upgrade_authority = Keypair::new();
+    let mut test = ProgramTest::default();
+    add_upgradeable_program(
+        &mut test,
+        "ctw_token.so",
+        &ctw_token::id(),
+        &upgrade_authority.pubkey(),
+    );
 
     test.add_program("spl_token_2022", spl_token_2022::id(), None);
+    test.add_program(
+        "spl_transfer_hook_example",
+        TRANSFER_HOOK_PROGRAM_ID,
+        processor!(spl_transfer_hook_example::processor::process),
+    );
 
     let mut account = solana_sdk::account::Account::new(
         u32::MAX as u64,
@@ -70,119 +145,5614 @@ pub async fn start_new_program_test() -> ProgramTestContext {
         &spl_token::id(),
     );
 
-    spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: COption::None,
-        decimals: 9,
-        ..spl_token::state::Mint::default()
+    spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: COption::None,
+        decimals: 9,
+        ..spl_token::state::Mint::default()
+    }
+    .pack_into_slice(&mut account.data);
+    test.add_account(native_mint::id(), account);
+
+    let context = test.start_with_context().await;
+    (context, upgrade_authority)
+}
+
+/// Adds the program and program data accounts of an upgradeable program with the given upgrade authority.
+fn add_upgradeable_program(
+    test: &mut ProgramTest,
+    program_file: &str,
+    program_id: &Pubkey,
+    upgrade_authority: &Pubkey,
+) {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+    // `UpgradeableLoaderState::ProgramData` followed by the program itself
+    let mut program_data = 3u32.to_le_bytes().to_vec();
+    program_data.extend_from_slice(&0u64.to_le_bytes());
+    program_data.push(1);
+    program_data.extend_from_slice(upgrade_authority.as_ref());
+    program_data.extend_from_slice(&read_file(find_file(program_file).unwrap()));
+
+    // `UpgradeableLoaderState::Program`
+    let mut program = 2u32.to_le_bytes().to_vec();
+    program.extend_from_slice(program_data_address.as_ref());
+
+    let rent = Rent::default();
+    test.add_account(
+        program_data_address,
+        solana_sdk::account::Account {
+            lamports: rent.minimum_balance(program_data.len()),
+            data: program_data,
+            owner: bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    test.add_account(
+        *program_id,
+        solana_sdk::account::Account {
+            lamports: rent.minimum_balance(program.len()),
+            data: program,
+            owner: bpf_loader_upgradeable::id(),
+            executable: true,
+            rent_epoch: 0,
+        },
+    );
+}
+
+#[tokio::test]
+async fn end_to_end() {
+    let mut test = start_new_program_test().await;
+
+    initialize(&mut test.banks_client, &test.payer, &native_mint::id())
+        .await
+        .unwrap();
+
+    println!("OK");
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &native_mint::id(),
+    )
+    .await
+    .unwrap();
+
+    println!("OK");
+
+    let amount = sol_to_lamports(1.0);
+
+    wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &native_mint::id(),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &native_mint::id()).await;
+    assert_eq!(wrapper_config.token_mint, native_mint::id());
+    assert_eq!(wrapper_config.token_program, spl_token::id());
+    assert_eq!(wrapper_config.total_wrapped, amount);
+    assert_eq!(wrapper_config.total_unwrapped, 0);
+
+    println!("OK");
+
+    post_wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &native_mint::id(),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    println!("OK");
+
+    withdraw_and_verify(
+        &mut test.banks_client,
+        &test.payer,
+        &native_mint::id(),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    println!("OK");
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &native_mint::id(),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &native_mint::id()).await;
+    assert_eq!(wrapper_config.total_wrapped, amount);
+    assert_eq!(wrapper_config.total_unwrapped, amount);
+}
+
+#[tokio::test]
+async fn end_to_end_token_extensions() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.token_program, spl_token_2022::id());
+    assert_eq!(
+        wrapper_config.token_vault,
+        derive_token_vault(&token_mint, 0, &spl_token_2022::id())
+    );
+    assert_eq!(wrapper_config.total_wrapped, amount);
+
+    verify_backing(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    unwrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    verify_backing(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn transfer_fee_mint() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let transfer_fee_basis_points = 100;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[ExtensionType::TransferFeeConfig],
+        |mint| {
+            vec![initialize_transfer_fee_config(
+                &spl_token_2022::id(),
+                mint,
+                None,
+                None,
+                transfer_fee_basis_points,
+                u64::MAX,
+            )
+            .unwrap()]
+        },
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    // Only the amount which arrived in the vault is minted
+    let wrapped_amount = amount - amount * transfer_fee_basis_points as u64 / 10_000;
+    let token_vault = derive_token_vault(&token_mint, 0, &spl_token_2022::id());
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, wrapped_amount);
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        wrapped_amount
+    );
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        wrapped_amount
+    );
+
+    post_wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    withdraw_and_verify(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    // The outbound transfer fee is withheld from what the user receives
+    let unwrapped_amount =
+        wrapped_amount - wrapped_amount * transfer_fee_basis_points as u64 / 10_000;
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        unwrapped_amount
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        0
+    );
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn transfer_hook_mint() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[ExtensionType::TransferHook],
+        |mint| {
+            vec![transfer_hook::instruction::initialize(
+                &spl_token_2022::id(),
+                mint,
+                None,
+                Some(TRANSFER_HOOK_PROGRAM_ID),
+            )
+            .unwrap()]
+        },
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize_extra_account_metas(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, amount);
+
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    unwrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn transfer_hook_mint_with_per_transfer_extra_accounts() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[ExtensionType::TransferHook],
+        |mint| {
+            vec![transfer_hook::instruction::initialize(
+                &spl_token_2022::id(),
+                mint,
+                None,
+                Some(TRANSFER_HOOK_PROGRAM_ID),
+            )
+            .unwrap()]
+        },
+        amount,
+    )
+    .await
+    .unwrap();
+
+    // The extra accounts differ between the transfer to or from the vault and the one into the fee vault
+    initialize_extra_account_metas_with(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        vec![
+            ExtraAccountMeta::new_with_seeds(&[Seed::AccountKey { index: 0 }], false, false)
+                .unwrap(),
+            ExtraAccountMeta::new_with_seeds(&[Seed::AccountKey { index: 2 }], false, false)
+                .unwrap(),
+        ],
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    set_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        100,
+        200,
+    )
+    .await
+    .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let wrapped_amount = amount - amount / 100;
+    post_wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    withdraw_and_verify(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    let (fee_vault, _) = derive_fee_vault(&token_mint, 0);
+    let fees = amount / 100 + wrapped_amount * 2 / 100;
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &fee_vault).await,
+        fees
+    );
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_fees, fees);
+}
+
+type InitializeExtensions = Box<dyn Fn(&Pubkey) -> Vec<Instruction>>;
+
+#[tokio::test]
+async fn initialize_rejects_hostile_mint_extensions() {
+    let mut test = start_new_program_test().await;
+
+    let authority = Keypair::new().pubkey();
+
+    let cases: Vec<(ExtensionType, InitializeExtensions, CtwError)> = vec![
+        (
+            ExtensionType::PermanentDelegate,
+            Box::new(move |mint| {
+                vec![
+                    initialize_permanent_delegate(&spl_token_2022::id(), mint, &authority).unwrap(),
+                ]
+            }),
+            CtwError::PermanentDelegateNotSupported,
+        ),
+        (
+            ExtensionType::NonTransferable,
+            Box::new(|mint| {
+                vec![initialize_non_transferable_mint(&spl_token_2022::id(), mint).unwrap()]
+            }),
+            CtwError::NonTransferableNotSupported,
+        ),
+        (
+            ExtensionType::InterestBearingConfig,
+            Box::new(move |mint| {
+                vec![interest_bearing_mint::instruction::initialize(
+                    &spl_token_2022::id(),
+                    mint,
+                    Some(authority),
+                    500,
+                )
+                .unwrap()]
+            }),
+            CtwError::InterestBearingNotSupported,
+        ),
+        (
+            ExtensionType::MintCloseAuthority,
+            Box::new(move |mint| {
+                vec![
+                    initialize_mint_close_authority(&spl_token_2022::id(), mint, Some(&authority))
+                        .unwrap(),
+                ]
+            }),
+            CtwError::MintCloseAuthorityNotSupported,
+        ),
+    ];
+
+    for (extension_type, initialize_extension, error) in cases {
+        println!("\nTesting {:?}..", extension_type);
+
+        let token_mint = create_mint_and_mint_to(
+            &mut test.banks_client,
+            &test.payer,
+            &spl_token_2022::id(),
+            &[extension_type],
+            initialize_extension,
+            1,
+        )
+        .await
+        .unwrap();
+
+        let result = initialize(&mut test.banks_client, &test.payer, &token_mint).await;
+        assert_ctw_error(result, error);
+    }
+}
+
+#[tokio::test]
+async fn initialize_rejects_frozen_default_account_state() {
+    let mut test = start_new_program_test().await;
+
+    // Tokens cannot be minted into a frozen account, so the default state is only set to frozen afterwards
+    let token_mint = create_mint_with_freeze_authority_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[ExtensionType::DefaultAccountState],
+        |mint| {
+            vec![initialize_default_account_state(
+                &spl_token_2022::id(),
+                mint,
+                &AccountState::Initialized,
+            )
+            .unwrap()]
+        },
+        Some(&test.payer.pubkey()),
+        1,
+    )
+    .await
+    .unwrap();
+
+    let update_default_account_state_ix = |state| {
+        update_default_account_state(
+            &spl_token_2022::id(),
+            &token_mint,
+            &test.payer.pubkey(),
+            &[],
+            &state,
+        )
+        .unwrap()
+    };
+
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[update_default_account_state_ix(AccountState::Frozen)],
+    )
+    .await
+    .unwrap();
+
+    // The vault would be created frozen
+    let result = initialize(&mut test.banks_client, &test.payer, &token_mint).await;
+    assert_ctw_error(result, CtwError::DefaultAccountStateFrozenNotSupported);
+
+    // While new accounts are initialized unfrozen, the mint may be wrapped
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[update_default_account_state_ix(AccountState::Initialized)],
+    )
+    .await
+    .unwrap();
+
+    // The same transaction as the failed one would otherwise be rejected as already processed
+    test.get_new_latest_blockhash().await.unwrap();
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn wrap_and_unwrap_reject_invalid_amounts() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, 0).await;
+    assert_ctw_error(result, CtwError::ZeroAmount);
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let result = unwrap(&mut test.banks_client, &test.payer, &token_mint, 0).await;
+    assert_ctw_error(result, CtwError::ZeroAmount);
+
+    // The wrapped amount was deposited into the confidential balance and never withdrawn
+    let result = unwrap(&mut test.banks_client, &test.payer, &token_mint, amount).await;
+    assert_ctw_error(result, CtwError::InsufficientPublicBalance);
+}
+
+#[tokio::test]
+async fn update_confidential_mint_by_admin() {
+    let mut test = start_new_program_test().await;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.admin, test.payer.pubkey());
+    assert_eq!(
+        wrapper_config.confidential_transfer_authority,
+        derive_wrapper_authority(&token_mint, 0).0
+    );
+    assert!(wrapper_config.auto_approve_new_accounts);
+
+    // Only the admin may update the Confidential Wrapped Token Mint
+    let result = update_confidential_mint(
+        &mut test.banks_client,
+        &test.payer,
+        &Keypair::new(),
+        &token_mint,
+        false,
+        None,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    update_confidential_mint(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        false,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert!(!wrapper_config.auto_approve_new_accounts);
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, [0; 32]);
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let account = test
+        .banks_client
+        .get_account(confidential_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    let confidential_transfer_mint = mint.get_extension::<ConfidentialTransferMint>().unwrap();
+    assert!(!bool::from(
+        confidential_transfer_mint.auto_approve_new_accounts
+    ));
+    assert!(
+        Option::<ElGamalPubkey>::from(confidential_transfer_mint.auditor_elgamal_pubkey).is_none()
+    );
+}
+
+#[tokio::test]
+async fn update_confidential_mint_requires_wrapper_authority() {
+    let mut test = start_new_program_test().await;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    initialize_with_options(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: false,
+            confidential_transfer_authority: Some(test.payer.pubkey()),
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(
+        wrapper_config.confidential_transfer_authority,
+        test.payer.pubkey()
+    );
+    assert!(!wrapper_config.auto_approve_new_accounts);
+
+    let result = update_confidential_mint(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        true,
+        None,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::ConfidentialTransferAuthorityNotProgram);
+}
+
+#[tokio::test]
+async fn rotate_auditor_records_history() {
+    let mut test = start_new_program_test().await;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    let initial_auditor = get_wrapper_config(&mut test.banks_client, &token_mint)
+        .await
+        .auditor_elgamal_pubkey;
+
+    let auditors: Vec<[u8; 32]> = (0..2)
+        .map(|_| ElGamalKeypair::new_rand().pubkey().to_bytes())
+        .collect();
+
+    for auditor in auditors.iter() {
+        rotate_auditor(&mut test.banks_client, &test.payer, &token_mint, *auditor)
+            .await
+            .unwrap();
+    }
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, auditors[1]);
+    assert_eq!(wrapper_config.auditor_rotations, 2);
+    assert_eq!(
+        wrapper_config.auditor_history[0].elgamal_pubkey,
+        initial_auditor
+    );
+    assert_eq!(
+        wrapper_config.auditor_history[1].elgamal_pubkey,
+        auditors[0]
+    );
+    assert!(wrapper_config.auto_approve_new_accounts);
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let account = test
+        .banks_client
+        .get_account(confidential_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    let confidential_transfer_mint = mint.get_extension::<ConfidentialTransferMint>().unwrap();
+    assert_eq!(
+        Option::<ElGamalPubkey>::from(confidential_transfer_mint.auditor_elgamal_pubkey),
+        Some(ElGamalPubkey(auditors[1]))
+    );
+}
+
+#[tokio::test]
+async fn initialize_auditor_requires_mint_authority_or_admin() {
+    let mut test = start_new_program_test().await;
+
+    let issuer = Keypair::new();
+    let attacker = Keypair::new();
+    for account in [&issuer, &attacker] {
+        fund_account(
+            &mut test.banks_client,
+            &test.payer,
+            &account.pubkey(),
+            sol_to_lamports(1.0),
+        )
+        .await
+        .unwrap();
+    }
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &issuer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    let auditor = ElGamalKeypair::new_rand().pubkey().to_bytes();
+
+    // Whoever initializes the wrapper first may not choose its auditor
+    let result = initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: Some(auditor),
+            variant: 0,
+            admin: None,
+        },
+        None,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::AuditorNotAuthorized);
+
+    let result = initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: false,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: Some(auditor),
+            variant: 0,
+            admin: None,
+        },
+        Some(&attacker),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::AuditorNotAuthorized);
+
+    // Nor its confidential transfer authority, which could set the auditor through the Token Extensions Program
+    let result = initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: Some(attacker.pubkey()),
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+        Some(&attacker),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::AuditorNotAuthorized);
+
+    // Nor who may hold the wrapped tokens, as new accounts would need to be approved
+    let result = initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: false,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+        Some(&attacker),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::ApprovalNotAuthorized);
+
+    initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: Some(auditor),
+            variant: 0,
+            admin: None,
+        },
+        Some(&issuer),
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, auditor);
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::MintAuthority);
+}
+
+#[tokio::test]
+async fn front_run_initialize_leaves_no_auditor() {
+    let mut test = start_new_program_test().await;
+
+    let issuer = Keypair::new();
+    let attacker = Keypair::new();
+    for account in [&issuer, &attacker] {
+        fund_account(
+            &mut test.banks_client,
+            &test.payer,
+            &account.pubkey(),
+            sol_to_lamports(1.0),
+        )
+        .await
+        .unwrap();
+    }
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &issuer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    // Anyone may still initialize the wrapper, but without choosing its admin
+    let result = initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: Some(attacker.pubkey()),
+        },
+        None,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::AdminNotAuthorized);
+
+    // Nor an auditor
+    initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+        None,
+    )
+    .await
+    .unwrap();
+
+    // The mint authority of the SPL Token Mint administers the wrapper instead of the payer
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.admin, issuer.pubkey());
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, [0; 32]);
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::None);
+
+    let attacker_auditor = ElGamalKeypair::new_rand().pubkey().to_bytes();
+    let result = rotate_auditor(
+        &mut test.banks_client,
+        &attacker,
+        &token_mint,
+        attacker_auditor,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let result = update_confidential_mint(
+        &mut test.banks_client,
+        &attacker,
+        &attacker,
+        &token_mint,
+        true,
+        Some(attacker_auditor),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    // The mint authority of the SPL Token Mint chooses the auditor instead
+    let auditor = ElGamalKeypair::new_rand().pubkey().to_bytes();
+    rotate_auditor(&mut test.banks_client, &issuer, &token_mint, auditor)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, auditor);
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::MintAuthority);
+
+    // The admin of the wrapper may keep the auditor while updating the wrapper
+    update_confidential_mint(
+        &mut test.banks_client,
+        &issuer,
+        &issuer,
+        &token_mint,
+        false,
+        Some(auditor),
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert!(!wrapper_config.auto_approve_new_accounts);
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::MintAuthority);
+
+    // As may the admin of the program
+    let auditor = ElGamalKeypair::new_rand().pubkey().to_bytes();
+    rotate_auditor(&mut test.banks_client, &test.payer, &token_mint, auditor)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::GlobalAdmin);
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let account = test
+        .banks_client
+        .get_account(confidential_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    let confidential_transfer_mint = mint.get_extension::<ConfidentialTransferMint>().unwrap();
+    assert_eq!(
+        Option::<ElGamalPubkey>::from(confidential_transfer_mint.auditor_elgamal_pubkey),
+        Some(ElGamalPubkey(auditor))
+    );
+}
+
+#[tokio::test]
+async fn initialize_admin_requires_mint_authority_or_admin() {
+    let mut test = start_new_program_test().await;
+
+    let issuer = Keypair::new();
+    let operator = Keypair::new();
+    for account in [&issuer, &operator] {
+        fund_account(
+            &mut test.banks_client,
+            &test.payer,
+            &account.pubkey(),
+            sol_to_lamports(1.0),
+        )
+        .await
+        .unwrap();
+    }
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &issuer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    // The mint authority of the SPL Token Mint may hand the wrapper to another admin
+    initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &operator,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: Some(operator.pubkey()),
+        },
+        Some(&issuer),
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.admin, operator.pubkey());
+
+    // As the mint authority chose the admin of the wrapper, the admin may rotate its auditor
+    let operator_auditor = ElGamalKeypair::new_rand().pubkey().to_bytes();
+    rotate_auditor(
+        &mut test.banks_client,
+        &operator,
+        &token_mint,
+        operator_auditor,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, operator_auditor);
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::WrapperAdmin);
+
+    let operator_auditor = ElGamalKeypair::new_rand().pubkey().to_bytes();
+    update_confidential_mint(
+        &mut test.banks_client,
+        &operator,
+        &operator,
+        &token_mint,
+        true,
+        Some(operator_auditor),
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.auditor_elgamal_pubkey, operator_auditor);
+    assert_eq!(wrapper_config.auditor_policy, AuditorPolicy::WrapperAdmin);
+}
+
+#[tokio::test]
+async fn initialize_survives_griefed_accounts() {
+    let mut test = start_new_program_test().await;
+
+    let griefer = Keypair::new();
+    fund_account(
+        &mut test.banks_client,
+        &test.payer,
+        &griefer.pubkey(),
+        sol_to_lamports(1.0),
+    )
+    .await
+    .unwrap();
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    for token_mint in [native_mint::id(), token_mint] {
+        println!("\nTesting {}..", token_mint);
+
+        let token_program = get_token_program(&mut test.banks_client, &token_mint)
+            .await
+            .unwrap();
+        let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+        let (fee_vault, _) = derive_fee_vault(&token_mint, 0);
+        let token_vault = derive_token_vault(&token_mint, 0, &token_program);
+
+        // Lamports sent to the PDAs, short of or beyond rent exemption, make `CreateAccount` fail. The runtime
+        // only lets an empty account be funded up to its own rent exemption, which falls short of the mint's
+        fund_account(
+            &mut test.banks_client,
+            &griefer,
+            &confidential_mint,
+            sol_to_lamports(0.001),
+        )
+        .await
+        .unwrap();
+        fund_account(
+            &mut test.banks_client,
+            &griefer,
+            &fee_vault,
+            sol_to_lamports(0.1),
+        )
+        .await
+        .unwrap();
+
+        // Anyone may create the vault, as it is the Associated Token Account of the wrapper authority
+        create_token_vault(&mut test.banks_client, &griefer, &token_mint)
+            .await
+            .unwrap();
+
+        initialize(&mut test.banks_client, &test.payer, &token_mint)
+            .await
+            .unwrap();
+
+        let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+        assert_eq!(wrapper_config.confidential_mint, confidential_mint);
+        assert_eq!(wrapper_config.token_vault, token_vault);
+        assert_eq!(wrapper_config.fee_vault, fee_vault);
+
+        let account = test
+            .banks_client
+            .get_account(confidential_mint)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(account.owner, token_2022::ID);
+        let rent = test.banks_client.get_rent().await.unwrap();
+        assert!(rent.is_exempt(account.lamports, account.data.len()));
+
+        let account = test
+            .banks_client
+            .get_account(fee_vault)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(account.owner, token_program);
+    }
+
+    // The wrapper works as if the program had created every account itself
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let token_program = get_token_program(&mut test.banks_client, &token_mint)
+        .await
+        .unwrap();
+    let token_vault = derive_token_vault(&token_mint, 0, &token_program);
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        amount
+    );
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn approval_required_wrapper() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize_with_options(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: false,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    // Unapproved accounts cannot receive Confidential Wrapped Tokens
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount).await;
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(
+                code,
+                TokenError::ConfidentialTransferAccountNotApproved as u32
+            )
+        }
+        e => panic!("Unexpected error: {:?}", e),
+    }
+
+    approve_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, amount);
+}
+
+#[tokio::test]
+async fn freeze_authority_pass_through() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_with_freeze_authority_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        Some(&test.payer.pubkey()),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    // The wrapper authority holds the freeze authority on behalf of the SPL Token Mint's freeze authority
+    let (wrapper_authority, _) = derive_wrapper_authority(&token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    assert_eq!(
+        get_mint_freeze_authority(&mut test.banks_client, &confidential_mint).await,
+        Some(wrapper_authority)
+    );
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4)
+        .await
+        .unwrap();
+
+    // Only the SPL Token Mint's freeze authority may freeze Confidential Token Accounts
+    let impostor = Keypair::new();
+    let result = set_account_frozen(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        &test.payer.pubkey(),
+        true,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::FreezeAuthorityMismatch);
+
+    set_account_frozen(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+        true,
+    )
+    .await
+    .unwrap();
+
+    let confidential_token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &confidential_mint,
+        &token_2022::ID,
+    );
+    assert!(is_token_account_frozen(&mut test.banks_client, &confidential_token_account).await);
+
+    // Frozen Confidential Token Accounts cannot receive Confidential Wrapped Tokens
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4).await;
+    assert!(result.is_err());
+
+    set_account_frozen(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+        false,
+    )
+    .await
+    .unwrap();
+    assert!(!is_token_account_frozen(&mut test.banks_client, &confidential_token_account).await);
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4)
+        .await
+        .unwrap();
+
+    // Frozen SPL Token Accounts cannot be wrapped from
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[freeze_account(
+            &spl_token_2022::id(),
+            &token_account,
+            &token_mint,
+            &test.payer.pubkey(),
+            &[],
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4).await;
+    assert_ctw_error(result, CtwError::TokenAccountFrozen);
+
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[thaw_account(
+            &spl_token_2022::id(),
+            &token_account,
+            &token_mint,
+            &test.payer.pubkey(),
+            &[],
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+
+    // Syncing an already synced freeze authority does nothing
+    sync_freeze_authority(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+    assert_eq!(
+        get_mint_freeze_authority(&mut test.banks_client, &confidential_mint).await,
+        Some(wrapper_authority)
+    );
+
+    // Once the SPL Token Mint's freeze authority is revoked, so is the Confidential Wrapped Token Mint's
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[set_authority(
+            &spl_token_2022::id(),
+            &token_mint,
+            None,
+            AuthorityType::FreezeAccount,
+            &test.payer.pubkey(),
+            &[],
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+
+    // The same transaction as the previous sync would otherwise be rejected as already processed
+    test.last_blockhash = test.banks_client.get_latest_blockhash().await.unwrap();
+    test.get_new_latest_blockhash().await.unwrap();
+    sync_freeze_authority(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+    assert_eq!(
+        get_mint_freeze_authority(&mut test.banks_client, &confidential_mint).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn sync_freeze_authority_cannot_restore_a_revoked_freeze_authority() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    // Without a freeze authority on the SPL Token Mint, there is none to sync
+    let token_mint = create_mint_with_freeze_authority_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        None,
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    sync_freeze_authority(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    assert_eq!(
+        get_mint_freeze_authority(&mut test.banks_client, &confidential_mint).await,
+        None
+    );
+
+    // The freeze authority copied onto a legacy Confidential Wrapped Token Mint may revoke it by itself
+    let token_mint = create_mint_with_freeze_authority_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        Some(&test.payer.pubkey()),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    revert_to_legacy_wrapper(&mut test, &token_mint).await;
+    adopt_legacy_wrapper(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[set_authority(
+            &token_2022::ID,
+            &confidential_mint,
+            None,
+            AuthorityType::FreezeAccount,
+            &test.payer.pubkey(),
+            &[],
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+
+    // Once revoked, the SPL Token Mint's freeze authority can no longer be mirrored
+    let result = sync_freeze_authority(&mut test.banks_client, &test.payer, &token_mint).await;
+    assert_ctw_error(result, CtwError::FreezeAuthorityRevoked);
+    assert_eq!(
+        get_mint_freeze_authority(&mut test.banks_client, &confidential_mint).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn initialize_global_requires_upgrade_authority() {
+    let (mut test, upgrade_authority) = start_new_program_test_without_global().await;
+
+    let admin = test.payer.pubkey();
+    let result = initialize_global(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &admin,
+        &admin,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::NotUpgradeAuthority);
+
+    let guardian = Pubkey::new_unique();
+    initialize_global(
+        &mut test.banks_client,
+        &test.payer,
+        &upgrade_authority,
+        &admin,
+        &guardian,
+    )
+    .await
+    .unwrap();
+
+    let global_config = get_global_config(&mut test.banks_client).await;
+    assert_eq!(global_config.admin, admin);
+    assert_eq!(global_config.guardian, guardian);
+    assert!(!global_config.paused);
+
+    // The global state can only be initialized once
+    let result = initialize_global(
+        &mut test.banks_client,
+        &test.payer,
+        &upgrade_authority,
+        &admin,
+        &guardian,
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn set_paused_blocks_wrap_and_unwrap() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4)
+        .await
+        .unwrap();
+
+    // Only the admin or guardian of the program or the admin of the wrapper may pause it
+    let impostor = Keypair::new();
+    let result = set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        Some(&token_mint),
+        true,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let result = set_paused(&mut test.banks_client, &test.payer, &impostor, None, true).await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    // Pausing the wrapper
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        Some(&token_mint),
+        true,
+    )
+    .await
+    .unwrap();
+    assert!(get_wrapper_config(&mut test.banks_client, &token_mint).await.paused);
+
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4).await;
+    assert_ctw_error(result, CtwError::Paused);
+    let result = unwrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4).await;
+    assert_ctw_error(result, CtwError::Paused);
+
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        Some(&token_mint),
+        false,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4)
+        .await
+        .unwrap();
+
+    // Pausing every wrapper
+    set_paused(&mut test.banks_client, &test.payer, &test.payer, None, true)
+        .await
+        .unwrap();
+    assert!(get_global_config(&mut test.banks_client).await.paused);
+
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4).await;
+    assert_ctw_error(result, CtwError::Paused);
+    let result = unwrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4).await;
+    assert_ctw_error(result, CtwError::Paused);
+
+    set_paused(&mut test.banks_client, &test.payer, &test.payer, None, false)
+        .await
+        .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, amount * 3 / 4);
+}
+
+#[tokio::test]
+async fn wrapper_admin_cannot_lift_guardian_pause() {
+    let mut test = start_new_program_test().await;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    let operator = Keypair::new();
+    initialize_with_options(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: Some(operator.pubkey()),
+        },
+    )
+    .await
+    .unwrap();
+
+    let guardian = Keypair::new();
+    set_guardian(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &guardian.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    // The admin of the wrapper may lift its own pause
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &operator,
+        Some(&token_mint),
+        true,
+    )
+    .await
+    .unwrap();
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &operator,
+        Some(&token_mint),
+        false,
+    )
+    .await
+    .unwrap();
+
+    // But not a pause by the guardian, even after pausing it again itself
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &guardian,
+        Some(&token_mint),
+        true,
+    )
+    .await
+    .unwrap();
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &operator,
+        Some(&token_mint),
+        true,
+    )
+    .await
+    .unwrap();
+    let result = set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &operator,
+        Some(&token_mint),
+        false,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert!(wrapper_config.paused);
+
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &guardian,
+        Some(&token_mint),
+        false,
+    )
+    .await
+    .unwrap();
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert!(!wrapper_config.paused);
+}
+
+#[tokio::test]
+async fn global_admin_two_step_transfer() {
+    let mut test = start_new_program_test().await;
+
+    let new_admin = Keypair::new();
+
+    // Only the admin may propose a new admin
+    let result = transfer_admin(
+        &mut test.banks_client,
+        &test.payer,
+        &new_admin,
+        &new_admin.pubkey(),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    transfer_admin(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &new_admin.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let global_config = get_global_config(&mut test.banks_client).await;
+    assert_eq!(global_config.admin, test.payer.pubkey());
+    assert_eq!(global_config.pending_admin, Some(new_admin.pubkey()));
+
+    // Only the pending admin may accept
+    let result = accept_admin(&mut test.banks_client, &test.payer, &Keypair::new()).await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    accept_admin(&mut test.banks_client, &test.payer, &new_admin)
+        .await
+        .unwrap();
+
+    let global_config = get_global_config(&mut test.banks_client).await;
+    assert_eq!(global_config.admin, new_admin.pubkey());
+    assert_eq!(global_config.pending_admin, None);
+
+    // The previous admin no longer has any say
+    let result = set_guardian(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &Pubkey::new_unique(),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let guardian = Keypair::new();
+    set_guardian(
+        &mut test.banks_client,
+        &test.payer,
+        &new_admin,
+        &guardian.pubkey(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_global_config(&mut test.banks_client).await.guardian,
+        guardian.pubkey()
+    );
+
+    // The guardian may pause every wrapper
+    set_paused(&mut test.banks_client, &test.payer, &guardian, None, true)
+        .await
+        .unwrap();
+    assert!(get_global_config(&mut test.banks_client).await.paused);
+}
+
+#[tokio::test]
+async fn default_policy_applies_to_new_wrappers() {
+    let mut test = start_new_program_test().await;
+
+    let wrapper_admin = Keypair::new();
+    set_default_policy(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        Some(wrapper_admin.pubkey()),
+        true,
+    )
+    .await
+    .unwrap();
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    // New wrappers get the default admin instead of the payer and start paused
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.admin, wrapper_admin.pubkey());
+    assert!(wrapper_config.paused);
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount).await;
+    assert_ctw_error(result, CtwError::Paused);
+
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &wrapper_admin,
+        Some(&token_mint),
+        false,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    // Both the admin of the wrapper and the admin of the program may update it
+    update_confidential_mint(
+        &mut test.banks_client,
+        &test.payer,
+        &wrapper_admin,
+        &token_mint,
+        false,
+        None,
+    )
+    .await
+    .unwrap();
+    assert!(
+        !get_wrapper_config(&mut test.banks_client, &token_mint)
+            .await
+            .auto_approve_new_accounts
+    );
+
+    update_confidential_mint(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        true,
+        None,
+    )
+    .await
+    .unwrap();
+    assert!(
+        get_wrapper_config(&mut test.banks_client, &token_mint)
+            .await
+            .auto_approve_new_accounts
+    );
+}
+
+#[tokio::test]
+async fn wrap_and_unwrap_fees() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let wrap_fee_bps = 100;
+    let unwrap_fee_bps = 200;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    let (fee_vault, _) = derive_fee_vault(&token_mint, 0);
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.fee_vault, fee_vault);
+    assert_eq!(wrapper_config.wrap_fee_bps, 0);
+    assert_eq!(wrapper_config.unwrap_fee_bps, 0);
+
+    // Only the admin of the wrapper or of the program may set the fees, up to the maximum fee
+    let impostor = Keypair::new();
+    let result = set_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        wrap_fee_bps,
+        unwrap_fee_bps,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let result = set_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        MAX_FEE_BPS + 1,
+        unwrap_fee_bps,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::FeeTooHigh);
+
+    set_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        wrap_fee_bps,
+        unwrap_fee_bps,
+    )
+    .await
+    .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    // The wrap fee is moved into the fee vault and only the remainder is minted
+    let wrap_fee = amount * wrap_fee_bps as u64 / 10_000;
+    let wrapped_amount = amount - wrap_fee;
+    let token_vault = derive_token_vault(&token_mint, 0, &spl_token_2022::id());
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        wrapped_amount
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &fee_vault).await,
+        wrap_fee
+    );
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        wrapped_amount
+    );
+
+    post_wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    withdraw_and_verify(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    // The unwrap fee is withheld from what the user receives
+    let unwrap_fee = wrapped_amount * unwrap_fee_bps as u64 / 10_000;
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        wrapped_amount - unwrap_fee
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        0
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &fee_vault).await,
+        wrap_fee + unwrap_fee
+    );
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, wrapped_amount);
+    assert_eq!(wrapper_config.total_unwrapped, wrapped_amount);
+    assert_eq!(wrapper_config.total_fees, wrap_fee + unwrap_fee);
+
+    // Only the admin of the wrapper or of the program may withdraw the fees
+    let result = withdraw_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        &token_account,
+        wrap_fee + unwrap_fee,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    withdraw_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &token_account,
+        wrap_fee + unwrap_fee,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        amount
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &fee_vault).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn wrap_and_unwrap_fees_with_transfer_fee_mint() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let transfer_fee_basis_points = 100;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[ExtensionType::TransferFeeConfig],
+        |mint| {
+            vec![initialize_transfer_fee_config(
+                &spl_token_2022::id(),
+                mint,
+                None,
+                None,
+                transfer_fee_basis_points,
+                u64::MAX,
+            )
+            .unwrap()]
+        },
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    set_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        100,
+        200,
+    )
+    .await
+    .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    // Moving the wrap fee into the fee vault is charged a transfer fee too, which is not collected
+    let (fee_vault, _) = derive_fee_vault(&token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let received_amount = amount - amount * transfer_fee_basis_points as u64 / 10_000;
+    let wrap_fee = received_amount / 100;
+    let collected_fees = get_token_account_amount(&mut test.banks_client, &fee_vault).await;
+    assert!(collected_fees < wrap_fee);
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_fees, collected_fees);
+
+    let wrapped_amount = get_mint_supply(&mut test.banks_client, &confidential_mint).await;
+    assert_eq!(wrapped_amount, received_amount - wrap_fee);
+
+    post_wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    withdraw_and_verify(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    // As is moving the unwrap fee
+    let unwrap_fee = wrapped_amount * 2 / 100;
+    let collected_fees = get_token_account_amount(&mut test.banks_client, &fee_vault).await;
+    assert!(collected_fees < wrap_fee + unwrap_fee);
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_fees, collected_fees);
+}
+
+#[tokio::test]
+async fn fees_require_mint_authority_or_admin() {
+    let mut test = start_new_program_test().await;
+
+    let issuer = Keypair::new();
+    let squatter = Keypair::new();
+    for account in [&issuer, &squatter] {
+        fund_account(
+            &mut test.banks_client,
+            &test.payer,
+            &account.pubkey(),
+            sol_to_lamports(1.0),
+        )
+        .await
+        .unwrap();
+    }
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &issuer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &squatter,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+        None,
+    )
+    .await
+    .unwrap();
+
+    // Whoever initialized the wrapper may neither set its fees nor withdraw them
+    let result = set_fees(
+        &mut test.banks_client,
+        &squatter,
+        &squatter,
+        &token_mint,
+        MAX_FEE_BPS,
+        MAX_FEE_BPS,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let token_account = get_associated_token_address_with_program_id(
+        &issuer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    let result = withdraw_fees(
+        &mut test.banks_client,
+        &squatter,
+        &squatter,
+        &token_mint,
+        &token_account,
+        0,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    // The mint authority of the SPL Token Mint administers the wrapper instead
+    set_fees(
+        &mut test.banks_client,
+        &issuer,
+        &issuer,
+        &token_mint,
+        100,
+        200,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.wrap_fee_bps, 100);
+    assert_eq!(wrapper_config.unwrap_fee_bps, 200);
+}
+
+#[tokio::test]
+async fn wrap_limits() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    // Only the admin of the wrapper or of the program may set the limits, which must be consistent
+    let impostor = Keypair::new();
+    let result = set_limits(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        None,
+        None,
+        0,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let result = set_limits(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        None,
+        Some(amount / 10),
+        amount / 5,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::InvalidLimits);
+
+    set_limits(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        Some(amount / 2),
+        Some(amount / 4),
+        amount / 10,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.max_supply, Some(amount / 2));
+    assert_eq!(wrapper_config.max_wrap_per_tx, Some(amount / 4));
+    assert_eq!(wrapper_config.min_wrap_amount, amount / 10);
+
+    let result = wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        amount / 20,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::WrapAmountTooSmall);
+
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 2).await;
+    assert_ctw_error(result, CtwError::WrapAmountTooLarge);
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 4)
+        .await
+        .unwrap();
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 5)
+        .await
+        .unwrap();
+
+    // The supply would exceed its maximum
+    let result = wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        amount / 10,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::MaxSupplyExceeded);
+
+    // Removing the limits allows wrapping the rest
+    set_limits(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        None,
+        None,
+        0,
+    )
+    .await
+    .unwrap();
+
+    wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        amount - amount / 4 - amount / 5,
+    )
+    .await
+    .unwrap();
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn unwrap_limit() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let window_slots = 100;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let guardian = Keypair::new();
+    set_guardian(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &guardian.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    // Only the admins and the guardian may set the unwrap limit, over a window of at least one slot
+    let impostor = Keypair::new();
+    let result = set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        Some(amount / 4),
+        window_slots,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let result = set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        Some(amount / 4),
+        0,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::InvalidUnwrapWindow);
+
+    set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        Some(amount / 4),
+        window_slots,
+    )
+    .await
+    .unwrap();
+
+    // The guardian may only tighten the unwrap limit
+    let result = set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &guardian,
+        &token_mint,
+        Some(amount / 2),
+        window_slots,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let result = set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &guardian,
+        &token_mint,
+        None,
+        window_slots,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &guardian,
+        &token_mint,
+        Some(amount / 5),
+        window_slots,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.unwrap_limit, Some(amount / 5));
+    assert_eq!(wrapper_config.unwrap_window_slots, window_slots);
+
+    unwrap(&mut test.banks_client, &test.payer, &token_mint, amount / 5)
+        .await
+        .unwrap();
+
+    // The capacity of the window is used up
+    let result = unwrap(&mut test.banks_client, &test.payer, &token_mint, 1).await;
+    assert_ctw_error(result, CtwError::UnwrapLimitExceeded);
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.unwrap_outflow, amount / 5);
+
+    // The capacity is released as the window passes
+    let slot = test.banks_client.get_root_slot().await.unwrap();
+    test.warp_to_slot(slot + window_slots).unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        amount / 10,
+    )
+    .await
+    .unwrap();
+
+    // Removing the unwrap limit allows unwrapping the rest
+    set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        None,
+        0,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        amount - amount / 5 - amount / 10,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_unwrapped, amount);
+}
+
+#[tokio::test]
+async fn unwrap_limit_caps_any_window() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let unwrap_limit = amount / 5;
+    let window_slots = 100;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    set_unwrap_limit(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        Some(unwrap_limit),
+        window_slots,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        unwrap_limit,
+    )
+    .await
+    .unwrap();
+
+    // One slot before a window has passed since, the full limit was still unwrapped within it
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    let slot = wrapper_config.unwrap_outflow_slot + window_slots - 1;
+    test.warp_to_slot(slot).unwrap();
+
+    assert_eq!(wrapper_config.remaining_unwrap_capacity(slot), Some(0));
+
+    // So what a linear refill would have released by then may not be unwrapped
+    let refill = unwrap_limit * (window_slots - 1) / window_slots;
+    let result = unwrap(&mut test.banks_client, &test.payer, &token_mint, refill).await;
+    assert_ctw_error(result, CtwError::UnwrapLimitExceeded);
+
+    // Once a full window has passed, the full limit may be unwrapped again
+    let slot = wrapper_config.unwrap_outflow_slot + window_slots;
+    test.warp_to_slot(slot).unwrap();
+
+    assert_eq!(
+        wrapper_config.remaining_unwrap_capacity(slot),
+        Some(unwrap_limit)
+    );
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        unwrap_limit,
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_unwrapped, unwrap_limit * 2);
+    assert_eq!(wrapper_config.unwrap_outflow, unwrap_limit);
+}
+
+#[tokio::test]
+async fn wrap_to_another_owner() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    // The recipient's account does not need to be configured for confidential transfers
+    let recipient = Keypair::new();
+    wrap_to(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &recipient.pubkey(),
+        amount / 2,
+    )
+    .await
+    .unwrap();
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient.pubkey(),
+        &confidential_mint,
+        &token_2022::ID,
+    );
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+
+    // The minted tokens are left in the public balance of the recipient
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &recipient_token_account).await,
+        amount / 2
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        amount / 2
+    );
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        amount / 2
+    );
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, amount / 2);
+}
+
+#[tokio::test]
+async fn wrap_to_requires_approval() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize_with_options(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: false,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    // An account which is not configured for confidential transfers cannot have been approved
+    let recipient = Keypair::new();
+    let result = wrap_to(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &recipient.pubkey(),
+        amount / 2,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::AccountNotApproved);
+
+    // Neither can an account which is configured but not approved yet
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+    let result = wrap_to(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+        amount / 2,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::AccountNotApproved);
+
+    approve_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+    )
+    .await
+    .unwrap();
+    wrap_to(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+        amount / 2,
+    )
+    .await
+    .unwrap();
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let confidential_token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &confidential_mint,
+        &token_2022::ID,
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &confidential_token_account).await,
+        amount / 2
+    );
+}
+
+#[tokio::test]
+async fn variants_have_their_own_wrapper_and_vaults() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token::id(),
+        &[],
+        |_| vec![],
+        amount * 2,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    // A second wrapper of the same SPL Token Mint, without an auditor and requiring approval
+    let variant_options = InitializeOptions {
+        auto_approve_new_accounts: false,
+        confidential_transfer_authority: None,
+        auditor_elgamal_pubkey: None,
+        variant: 1,
+        admin: None,
+    };
+    initialize_with_options(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        variant_options.clone(),
+    )
+    .await
+    .unwrap();
+
+    let result = initialize_with_options(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        variant_options,
+    )
+    .await;
+    assert!(result.is_err());
+
+    // The first variant keeps the address of the wrappers created before variants existed
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    assert_eq!(
+        confidential_mint,
+        Pubkey::find_program_address(&[token_mint.as_ref(), b"MINT"], &ctw_token::ID).0
+    );
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    let variant_wrapper_config =
+        get_wrapper_config_variant(&mut test.banks_client, &token_mint, 1).await;
+    assert_eq!(wrapper_config.variant, 0);
+    assert_eq!(variant_wrapper_config.variant, 1);
+    assert_eq!(
+        variant_wrapper_config.confidential_mint,
+        derive_confidential_mint(&token_mint, 1).0
+    );
+    assert_ne!(
+        variant_wrapper_config.confidential_mint,
+        wrapper_config.confidential_mint
+    );
+    assert_ne!(
+        variant_wrapper_config.token_vault,
+        wrapper_config.token_vault
+    );
+    assert_ne!(variant_wrapper_config.fee_vault, wrapper_config.fee_vault);
+    assert!(wrapper_config.auto_approve_new_accounts);
+    assert!(!variant_wrapper_config.auto_approve_new_accounts);
+    assert_ne!(wrapper_config.auditor_elgamal_pubkey, [0; 32]);
+    assert_eq!(variant_wrapper_config.auditor_elgamal_pubkey, [0; 32]);
+
+    wrap_to(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+        amount,
+    )
+    .await
+    .unwrap();
+
+    // The second variant requires the account to be approved before it can be wrapped to
+    create_and_configure_confidential_token_account_variant(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        1,
+    )
+    .await
+    .unwrap();
+    approve_account_variant(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        1,
+        &test.payer.pubkey(),
+    )
+    .await
+    .unwrap();
+    wrap_to_variant(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        1,
+        &test.payer.pubkey(),
+        amount / 4,
+    )
+    .await
+    .unwrap();
+
+    // Each variant is only backed by its own vault
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &wrapper_config.token_vault).await,
+        amount
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &variant_wrapper_config.token_vault).await,
+        amount / 4
+    );
+    assert_eq!(
+        get_mint_supply(
+            &mut test.banks_client,
+            &variant_wrapper_config.confidential_mint
+        )
+        .await,
+        amount / 4
+    );
+
+    unwrap_variant(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        1,
+        amount / 4,
+    )
+    .await
+    .unwrap();
+
+    let result = unwrap_variant(&mut test.banks_client, &test.payer, &token_mint, 1, 1).await;
+    assert!(result.is_err());
+
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &wrapper_config.token_vault).await,
+        amount
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &variant_wrapper_config.token_vault).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn sweep_surplus_into_treasury() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount / 2)
+        .await
+        .unwrap();
+
+    let token_vault = derive_token_vault(&token_mint, 0, &spl_token_2022::id());
+    let (fee_vault, _) = derive_fee_vault(&token_mint, 0);
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+
+    // The surplus cannot be swept until a treasury is set
+    let result = sweep_surplus(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &token_vault,
+    )
+    .await;
+    assert_ctw_error(result, CtwError::TreasuryNotSet);
+
+    // Only the admin of the wrapper or of the program may set the treasury
+    let impostor = Keypair::new();
+    let result = set_treasury(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        Some(impostor.pubkey()),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    set_treasury(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        Some(token_account),
+    )
+    .await
+    .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.treasury, Some(token_account));
+
+    // The surplus may only be swept into the treasury
+    let result = sweep_surplus(&mut test.banks_client, &test.payer, &token_mint, &fee_vault).await;
+    assert_ctw_error(result, CtwError::InvalidTreasury);
+
+    // Tokens sent straight to the vault are not backing any supply
+    transfer_tokens(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &token_vault,
+        amount / 4,
+    )
+    .await
+    .unwrap();
+
+    sweep_surplus(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        &token_account,
+    )
+    .await
+    .unwrap();
+
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        amount / 2
+    );
+    assert_eq!(
+        get_mint_supply(&mut test.banks_client, &confidential_mint).await,
+        amount / 2
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_account).await,
+        amount / 2
+    );
+}
+
+#[tokio::test]
+async fn close_wrapper_once_empty() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let wrap_fee_bps = 100;
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        amount,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    set_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        wrap_fee_bps,
+        0,
+    )
+    .await
+    .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    // The wrapper cannot be closed while its Confidential Wrapped Token is in circulation
+    let result = close_wrapper(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::SupplyNotZero);
+
+    let wrap_fee = amount * wrap_fee_bps as u64 / 10_000;
+    let wrapped_amount = amount - wrap_fee;
+
+    post_wrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    withdraw_and_verify(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    unwrap(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+        wrapped_amount,
+    )
+    .await
+    .unwrap();
+
+    // Nor while the fee vault still holds fees
+    let result = close_wrapper(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &test.payer.pubkey(),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::VaultNotEmpty);
+
+    let token_account = get_associated_token_address_with_program_id(
+        &test.payer.pubkey(),
+        &token_mint,
+        &spl_token_2022::id(),
+    );
+    withdraw_fees(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &token_account,
+        wrap_fee,
+    )
+    .await
+    .unwrap();
+
+    // Only the mint authority of the SPL Token Mint or the admin of the program may close it
+    let impostor = Keypair::new();
+    let result = close_wrapper(
+        &mut test.banks_client,
+        &test.payer,
+        &impostor,
+        &token_mint,
+        &impostor.pubkey(),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    let destination = Pubkey::new_unique();
+    close_wrapper(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        &token_mint,
+        &destination,
+    )
+    .await
+    .unwrap();
+
+    // The vaults and the state are closed, their rent refunded, and the mint is disabled
+    let (wrapper_config, _) = derive_wrapper_config(&token_mint, 0);
+    let (fee_vault, _) = derive_fee_vault(&token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let token_vault = derive_token_vault(&token_mint, 0, &spl_token_2022::id());
+    for account in [wrapper_config, fee_vault, token_vault] {
+        assert!(test
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+    assert!(test.banks_client.get_balance(destination).await.unwrap() > 0);
+    assert_eq!(
+        get_mint_authority(&mut test.banks_client, &confidential_mint).await,
+        None
+    );
+
+    // Without its state, the wrapper can no longer be used
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount).await;
+    assert!(matches!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(_, InstructionError::Custom(code))
+            if code == u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized)
+    ));
+}
+
+#[tokio::test]
+async fn close_wrapper_requires_mint_authority_or_admin() {
+    let mut test = start_new_program_test().await;
+
+    let issuer = Keypair::new();
+    let squatter = Keypair::new();
+    for account in [&issuer, &squatter] {
+        fund_account(
+            &mut test.banks_client,
+            &test.payer,
+            &account.pubkey(),
+            sol_to_lamports(1.0),
+        )
+        .await
+        .unwrap();
+    }
+
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &issuer,
+        &spl_token_2022::id(),
+        &[],
+        |_| vec![],
+        1,
+    )
+    .await
+    .unwrap();
+
+    // Whoever initializes the wrapper first may not close it for good
+    initialize_with_auditor_authority(
+        &mut test.banks_client,
+        &squatter,
+        &token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: None,
+            variant: 0,
+            admin: None,
+        },
+        None,
+    )
+    .await
+    .unwrap();
+
+    let result = close_wrapper(
+        &mut test.banks_client,
+        &squatter,
+        &squatter,
+        &token_mint,
+        &squatter.pubkey(),
+    )
+    .await;
+    assert_ctw_error(result, CtwError::Unauthorized);
+
+    // The mint authority of the SPL Token Mint may
+    close_wrapper(
+        &mut test.banks_client,
+        &issuer,
+        &issuer,
+        &token_mint,
+        &issuer.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    // And the wrapper cannot be initialized again
+    let result = initialize(&mut test.banks_client, &issuer, &token_mint).await;
+    assert_ctw_error(result, CtwError::MintAlreadyWrapped);
+}
+
+#[tokio::test]
+async fn migrate_authority_moves_legacy_wrapper() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let token_mint = create_mint_with_freeze_authority_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token::id(),
+        &[],
+        |_| vec![],
+        Some(&test.payer.pubkey()),
+        amount * 2,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    revert_to_program_authority(&mut test, &token_mint).await;
+
+    // The wrapper cannot be used under its own authority before being migrated
+    let result = wrap(&mut test.banks_client, &test.payer, &token_mint, amount).await;
+    assert!(result.is_err());
+
+    // Anyone may migrate the wrapper
+    let migrator = Keypair::new();
+    fund_account(
+        &mut test.banks_client,
+        &test.payer,
+        &migrator.pubkey(),
+        sol_to_lamports(1.0),
+    )
+    .await
+    .unwrap();
+
+    let legacy_token_vault = derive_legacy_token_vault(&token_mint, &spl_token::id());
+    let legacy_token_vault_rent = test
+        .banks_client
+        .get_account(legacy_token_vault)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let admin_balance = test
+        .banks_client
+        .get_balance(test.payer.pubkey())
+        .await
+        .unwrap();
+
+    migrate_authority(&mut test.banks_client, &migrator, &token_mint)
+        .await
+        .unwrap();
+
+    // The rent of the vault of the shared authority goes to the admin of the wrapper, not to the migrator
+    assert_eq!(
+        test.banks_client
+            .get_balance(test.payer.pubkey())
+            .await
+            .unwrap(),
+        admin_balance + legacy_token_vault_rent
+    );
+
+    let (wrapper_authority, _) = derive_wrapper_authority(&token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(&token_mint, 0);
+    let token_vault = derive_token_vault(&token_mint, 0, &spl_token::id());
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.token_vault, token_vault);
+    assert_eq!(
+        wrapper_config.confidential_transfer_authority,
+        wrapper_authority
+    );
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        amount
+    );
+    assert!(test
+        .banks_client
+        .get_account(legacy_token_vault)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        get_mint_authority(&mut test.banks_client, &confidential_mint).await,
+        Some(wrapper_authority)
+    );
+    assert_eq!(
+        get_mint_freeze_authority(&mut test.banks_client, &confidential_mint).await,
+        Some(wrapper_authority)
+    );
+
+    // A wrapper is only migrated once
+    let result = migrate_authority(&mut test.banks_client, &test.payer, &token_mint).await;
+    assert_ctw_error(result, CtwError::AuthorityAlreadyMigrated);
+
+    // The wrapper keeps working under its own authority
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount * 2)
+        .await
+        .unwrap();
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    unwrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn adopt_legacy_wrapper_reconstructs_wrapper_config() {
+    let mut test = start_new_program_test().await;
+
+    let amount = 1_000_000;
+    let token_mint = create_mint_with_freeze_authority_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token::id(),
+        &[],
+        |_| vec![],
+        Some(&test.payer.pubkey()),
+        amount * 2,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    revert_to_legacy_wrapper(&mut test, &token_mint).await;
+
+    set_default_policy(&mut test.banks_client, &test.payer, &test.payer, None, true)
+        .await
+        .unwrap();
+
+    // Without a default wrapper admin, the mint authority administers the wrapper as it would a new one
+    let issuer = Keypair::new();
+    process_instructions(
+        &mut test.banks_client,
+        &test.payer,
+        &[set_authority(
+            &spl_token::id(),
+            &token_mint,
+            Some(&issuer.pubkey()),
+            AuthorityType::MintTokens,
+            &test.payer.pubkey(),
+            &[],
+        )
+        .unwrap()],
+    )
+    .await
+    .unwrap();
+
+    // Anyone may adopt the wrapper, without becoming its admin
+    let adopter = Keypair::new();
+    fund_account(
+        &mut test.banks_client,
+        &test.payer,
+        &adopter.pubkey(),
+        sol_to_lamports(1.0),
+    )
+    .await
+    .unwrap();
+    adopt_legacy_wrapper(&mut test.banks_client, &adopter, &token_mint)
+        .await
+        .unwrap();
+
+    let (program_authority, _) = derive_authority();
+    let adopted_wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(adopted_wrapper_config.admin, issuer.pubkey());
+    assert_eq!(
+        adopted_wrapper_config.token_vault,
+        derive_legacy_token_vault(&token_mint, &spl_token::id())
+    );
+    assert_eq!(
+        adopted_wrapper_config.confidential_transfer_authority,
+        program_authority
+    );
+    assert_eq!(
+        adopted_wrapper_config.auto_approve_new_accounts,
+        wrapper_config.auto_approve_new_accounts
+    );
+    assert_eq!(
+        adopted_wrapper_config.auditor_elgamal_pubkey,
+        wrapper_config.auditor_elgamal_pubkey
+    );
+    assert_eq!(adopted_wrapper_config.total_wrapped, amount);
+
+    // Like new wrappers, it follows the default policy of the program
+    assert!(adopted_wrapper_config.paused);
+
+    // A wrapper is only adopted once
+    let result = adopt_legacy_wrapper(&mut test.banks_client, &test.payer, &token_mint).await;
+    assert!(result.is_err());
+
+    set_paused(
+        &mut test.banks_client,
+        &test.payer,
+        &test.payer,
+        Some(&token_mint),
+        false,
+    )
+    .await
+    .unwrap();
+
+    // Once moved to its own authority, the adopted wrapper works as any other
+    migrate_authority(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount * 2)
+        .await
+        .unwrap();
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    unwrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let token_vault = derive_token_vault(&token_mint, 0, &spl_token::id());
+    assert_eq!(
+        get_token_account_amount(&mut test.banks_client, &token_vault).await,
+        amount
+    );
+}
+
+#[tokio::test]
+async fn upgrade_requires_initialize_global() {
+    let (mut test, upgrade_authority) = start_new_program_test_without_global().await;
+
+    let admin = test.payer.pubkey();
+    initialize_global(
+        &mut test.banks_client,
+        &test.payer,
+        &upgrade_authority,
+        &admin,
+        &admin,
+    )
+    .await
+    .unwrap();
+
+    let amount = 1_000_000;
+    let token_mint = create_mint_and_mint_to(
+        &mut test.banks_client,
+        &test.payer,
+        &spl_token::id(),
+        &[],
+        |_| vec![],
+        amount * 2,
+    )
+    .await
+    .unwrap();
+
+    initialize(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    create_and_configure_confidential_token_account(
+        &mut test.banks_client,
+        &test.payer,
+        &token_mint,
+    )
+    .await
+    .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    // A deployment from before the Global Config existed, right after the program is upgraded
+    revert_to_legacy_wrapper(&mut test, &token_mint).await;
+    let (global_config, _) = derive_global_config();
+    test.set_account(&global_config, &AccountSharedData::default());
+
+    let result = adopt_legacy_wrapper(&mut test.banks_client, &test.payer, &token_mint).await;
+    assert!(matches!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(_, InstructionError::Custom(code))
+            if code == u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized)
+    ));
+
+    // Once the upgrade authority initializes the Global Config, existing wrappers can be adopted and used again
+    initialize_global(
+        &mut test.banks_client,
+        &test.payer,
+        &upgrade_authority,
+        &admin,
+        &admin,
+    )
+    .await
+    .unwrap();
+
+    adopt_legacy_wrapper(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+    migrate_authority(&mut test.banks_client, &test.payer, &token_mint)
+        .await
+        .unwrap();
+
+    wrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    post_wrap(&mut test.banks_client, &test.payer, &token_mint, amount * 2)
+        .await
+        .unwrap();
+    withdraw_and_verify(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+    unwrap(&mut test.banks_client, &test.payer, &token_mint, amount)
+        .await
+        .unwrap();
+
+    let wrapper_config = get_wrapper_config(&mut test.banks_client, &token_mint).await;
+    assert_eq!(wrapper_config.total_wrapped, amount * 2);
+}
+
+#[test]
+fn events_are_decoded_from_logs() {
+    let wrapped = Wrapped {
+        token_mint: Pubkey::new_unique(),
+        confidential_mint: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: 1_000_000,
+        fee: 0,
+        vault_balance: 1_000_000,
+        slot: 1,
+    };
+    let limits_updated = LimitsUpdated {
+        token_mint: wrapped.token_mint,
+        max_supply: Some(1_000_000),
+        max_wrap_per_tx: None,
+        min_wrap_amount: 1,
+        admin: Pubkey::new_unique(),
+        slot: 2,
+    };
+
+    let logs = vec![
+        format!("Program {} invoke [1]", ctw_token::id()),
+        "Program log: Instruction: Wrap".to_string(),
+        format!(
+            "Program data: {}",
+            anchor_lang::__private::base64::encode(wrapped.data())
+        ),
+        format!("Program {} success", ctw_token::id()),
+        format!("Program {} invoke [1]", ctw_token::id()),
+        "Program log: Instruction: SetLimits".to_string(),
+        format!(
+            "Program data: {}",
+            anchor_lang::__private::base64::encode(limits_updated.data())
+        ),
+        format!("Program {} success", ctw_token::id()),
+    ];
+
+    let events = CtwEvent::from_logs(&logs);
+    assert_eq!(events.len(), 2);
+    match &events[0] {
+        CtwEvent::Wrapped(event) => {
+            assert_eq!(event.token_mint, wrapped.token_mint);
+            assert_eq!(event.owner, wrapped.owner);
+            assert_eq!(event.recipient, wrapped.recipient);
+            assert_eq!(event.amount, wrapped.amount);
+        }
+        e => panic!("Unexpected event: {:?}", e),
+    }
+    match &events[1] {
+        CtwEvent::LimitsUpdated(event) => {
+            assert_eq!(event.max_supply, limits_updated.max_supply);
+            assert_eq!(event.max_wrap_per_tx, limits_updated.max_wrap_per_tx);
+            assert_eq!(event.admin, limits_updated.admin);
+        }
+        e => panic!("Unexpected event: {:?}", e),
+    }
+}
+
+#[test]
+fn events_logged_by_other_programs_are_ignored() {
+    let wrapped = Wrapped {
+        token_mint: Pubkey::new_unique(),
+        confidential_mint: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: 1_000_000,
+        fee: 0,
+        vault_balance: 1_000_000,
+        slot: 1,
+    };
+    let fake = Wrapped {
+        amount: u64::MAX,
+        ..wrapped.clone()
+    };
+    let event_data = |event: &Wrapped| {
+        format!(
+            "Program data: {}",
+            anchor_lang::__private::base64::encode(event.data())
+        )
+    };
+
+    let other_program = Pubkey::new_unique();
+    let logs = vec![
+        // Another program of the transaction
+        format!("Program {} invoke [1]", other_program),
+        event_data(&fake),
+        format!("Program {} success", other_program),
+        format!("Program {} invoke [1]", ctw_token::id()),
+        "Program log: Instruction: Wrap".to_string(),
+        // A program invoked by the Confidential Wrapped Token Program, such as a transfer hook, pretending to return
+        format!("Program {} invoke [2]", other_program),
+        event_data(&fake),
+        "Program log: success".to_string(),
+        format!("Program log: {} success", other_program),
+        event_data(&fake),
+        format!("Program {} success", other_program),
+        event_data(&wrapped),
+        format!("Program {} success", ctw_token::id()),
+    ];
+
+    let events = CtwEvent::from_logs(&logs);
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        CtwEvent::Wrapped(event) => assert_eq!(event.amount, wrapped.amount),
+        e => panic!("Unexpected event: {:?}", e),
+    }
+}
+
+#[test]
+fn errors_are_decoded_from_their_codes() {
+    // The IDL is generated from `CtwError`, so it lists every error, unlike the hand-written decoding
+    let idl = include_str!("../../../ts/src/program.ts");
+    let idl = &idl[idl.find("export const IDL").unwrap()..];
+    let errors = &idl[idl.find("  errors: [").unwrap()..];
+
+    let mut last_code = None;
+    for error in errors.split("code: ").skip(1) {
+        let (code, rest) = error.split_once(',').unwrap();
+        let code = code.parse::<u32>().unwrap();
+        let name = rest.split('"').nth(1).unwrap();
+
+        let error = CtwError::try_from(code)
+            .unwrap_or_else(|code| panic!("The error {} ({}) is not decoded", name, code));
+        assert_eq!(error.name(), name);
+        assert_eq!(u32::from(error), code);
+        last_code = Some(code);
+    }
+
+    assert!(CtwError::try_from(last_code.unwrap() + 1).is_err());
+}
+
+fn assert_ctw_error(result: Result<(), BanksClientError>, error: CtwError) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, u32::from(error))
+        }
+        e => panic!("Unexpected error: {:?}", e),
+    }
+}
+
+async fn get_token_account_amount(banks_client: &mut BanksClient, token_account: &Pubkey) -> u64 {
+    let account = banks_client
+        .get_account(*token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    StateWithExtensions::<Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+async fn get_mint_supply(banks_client: &mut BanksClient, mint: &Pubkey) -> u64 {
+    let account = banks_client.get_account(*mint).await.unwrap().unwrap();
+
+    StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .base
+        .supply
+}
+
+async fn initialize_extra_account_metas(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    // Require an extra account so the pass-through of remaining accounts is exercised
+    initialize_extra_account_metas_with(
+        banks_client,
+        signer,
+        token_mint,
+        vec![ExtraAccountMeta::new_with_pubkey(&sysvar::clock::id(), false, false).unwrap()],
+    )
+    .await
+}
+
+async fn initialize_extra_account_metas_with(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    extra_account_metas: Vec<ExtraAccountMeta>,
+) -> Result<(), BanksClientError> {
+    println!("\nInitializing transfer hook extra account metas..");
+
+    let extra_account_metas_address =
+        get_extra_account_metas_address(token_mint, &TRANSFER_HOOK_PROGRAM_ID);
+
+    let rent = banks_client.get_rent().await.unwrap();
+    let space = ExtraAccountMetaList::size_of(extra_account_metas.len()).unwrap();
+
+    let ixs = vec![
+        transfer(
+            &signer.pubkey(),
+            &extra_account_metas_address,
+            rent.minimum_balance(space),
+        ),
+        initialize_extra_account_meta_list(
+            &TRANSFER_HOOK_PROGRAM_ID,
+            &extra_account_metas_address,
+            token_mint,
+            &signer.pubkey(),
+            &extra_account_metas,
+        ),
+    ];
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn get_transfer_hook_accounts(
+    banks_client: &mut BanksClient,
+    token_program: &Pubkey,
+    source: &Pubkey,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Vec<AccountMeta> {
+    let fetch_account_data_fn = |address| {
+        let mut banks_client = banks_client.clone();
+        async move {
+            Ok(banks_client
+                .get_account(address)
+                .await
+                .unwrap()
+                .map(|a| a.data))
+        }
+    };
+
+    resolve_transfer_hook_accounts(
+        fetch_account_data_fn,
+        token_program,
+        source,
+        token_mint,
+        destination,
+        authority,
+        amount,
+    )
+    .await
+    .unwrap()
+}
+
+async fn get_token_program(
+    banks_client: &mut BanksClient,
+    token_mint: &Pubkey,
+) -> Result<Pubkey, BanksClientError> {
+    let account = banks_client.get_account(*token_mint).await?.unwrap();
+    Ok(account.owner)
+}
+
+async fn create_mint_and_mint_to(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_program: &Pubkey,
+    extension_types: &[ExtensionType],
+    initialize_extensions: impl FnOnce(&Pubkey) -> Vec<Instruction>,
+    amount: u64,
+) -> Result<Pubkey, BanksClientError> {
+    create_mint_with_freeze_authority_and_mint_to(
+        banks_client,
+        signer,
+        token_program,
+        extension_types,
+        initialize_extensions,
+        None,
+        amount,
+    )
+    .await
+}
+
+async fn create_mint_with_freeze_authority_and_mint_to(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_program: &Pubkey,
+    extension_types: &[ExtensionType],
+    initialize_extensions: impl FnOnce(&Pubkey) -> Vec<Instruction>,
+    freeze_authority: Option<&Pubkey>,
+    amount: u64,
+) -> Result<Pubkey, BanksClientError> {
+    println!("\nCreating SPL Token Mint..");
+
+    let mint = Keypair::new();
+    let token_account = get_associated_token_address_with_program_id(
+        &signer.pubkey(),
+        &mint.pubkey(),
+        token_program,
+    );
+
+    let space = ExtensionType::try_calculate_account_len::<Mint>(extension_types).unwrap();
+    let rent = banks_client.get_rent().await.unwrap();
+
+    let mut ixs = vec![create_account(
+        &signer.pubkey(),
+        &mint.pubkey(),
+        rent.minimum_balance(space),
+        space as u64,
+        token_program,
+    )];
+    ixs.extend(initialize_extensions(&mint.pubkey()));
+    ixs.extend(vec![
+        initialize_mint2(
+            token_program,
+            &mint.pubkey(),
+            &signer.pubkey(),
+            freeze_authority,
+            6,
+        )
+        .unwrap(),
+        create_associated_token_account(
+            &signer.pubkey(),
+            &signer.pubkey(),
+            &mint.pubkey(),
+            token_program,
+        ),
+        mint_to(
+            token_program,
+            &mint.pubkey(),
+            &token_account,
+            &signer.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap(),
+    ]);
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&signer.pubkey()),
+        &[signer, &mint],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(mint.pubkey()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn get_mint_authority(banks_client: &mut BanksClient, mint: &Pubkey) -> Option<Pubkey> {
+    let account = banks_client.get_account(*mint).await.unwrap().unwrap();
+    StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .base
+        .mint_authority
+        .into()
+}
+
+async fn get_mint_freeze_authority(banks_client: &mut BanksClient, mint: &Pubkey) -> Option<Pubkey> {
+    let account = banks_client.get_account(*mint).await.unwrap().unwrap();
+    StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .base
+        .freeze_authority
+        .into()
+}
+
+async fn is_token_account_frozen(banks_client: &mut BanksClient, token_account: &Pubkey) -> bool {
+    let account = banks_client
+        .get_account(*token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    StateWithExtensions::<Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .is_frozen()
+}
+
+async fn process_instructions(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    ixs: &[Instruction],
+) -> Result<(), BanksClientError> {
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn get_global_config(banks_client: &mut BanksClient) -> GlobalConfig {
+    let (global_config, _) = derive_global_config();
+    let account = banks_client
+        .get_account(global_config)
+        .await
+        .unwrap()
+        .unwrap();
+    GlobalConfig::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+async fn get_wrapper_config(banks_client: &mut BanksClient, token_mint: &Pubkey) -> WrapperConfig {
+    get_wrapper_config_variant(banks_client, token_mint, 0).await
+}
+
+async fn get_wrapper_config_variant(
+    banks_client: &mut BanksClient,
+    token_mint: &Pubkey,
+    variant: u16,
+) -> WrapperConfig {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let account = banks_client
+        .get_account(wrapper_config)
+        .await
+        .unwrap()
+        .unwrap();
+
+    WrapperConfig::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+async fn initialize(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let elgamal_keypair = ElGamalKeypair::new_from_signer(signer, "auditor".as_ref()).unwrap();
+
+    initialize_with_options(
+        banks_client,
+        signer,
+        token_mint,
+        InitializeOptions {
+            auto_approve_new_accounts: true,
+            confidential_transfer_authority: None,
+            auditor_elgamal_pubkey: Some(elgamal_keypair.pubkey().to_bytes()),
+            variant: 0,
+            admin: None,
+        },
+    )
+    .await
+}
+
+async fn initialize_with_options(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    options: InitializeOptions,
+) -> Result<(), BanksClientError> {
+    initialize_with_auditor_authority(banks_client, signer, token_mint, options, Some(signer)).await
+}
+
+async fn initialize_with_auditor_authority(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    options: InitializeOptions,
+    auditor_authority: Option<&Keypair>,
+) -> Result<(), BanksClientError> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, options.variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, options.variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, options.variant);
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, options.variant);
+
+    println!(
+        "Creating Confidential Wrapped Token Mint: {}",
+        confidential_mint
+    );
+
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, options.variant, &token_program);
+
+    let ix = Instruction {
+        accounts: Initialize {
+            token_mint: *token_mint,
+            wrapper_authority,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            token_vault,
+            fee_vault,
+            payer: signer.pubkey(),
+            auditor_authority: auditor_authority.map(|keypair| keypair.pubkey()),
+            token_program,
+            associated_token_program: associated_token::ID,
+            token_extensions_program: token_2022::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::Initialize { options }.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let mut signers = vec![signer];
+    signers.extend(auditor_authority);
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &signers,
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn update_confidential_mint(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    token_mint: &Pubkey,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+) -> Result<(), BanksClientError> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+
+    let ix = Instruction {
+        accounts: UpdateConfidentialMint {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            wrapper_authority,
+            admin: admin.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::UpdateConfidentialMint {
+            auto_approve_new_accounts,
+            auditor_elgamal_pubkey,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn rotate_auditor(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    auditor_elgamal_pubkey: [u8; 32],
+) -> Result<(), BanksClientError> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+
+    let ix = Instruction {
+        accounts: UpdateConfidentialMint {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::RotateAuditor {
+            auditor_elgamal_pubkey,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn approve_account(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<(), BanksClientError> {
+    approve_account_variant(banks_client, signer, token_mint, 0, owner).await
+}
+
+async fn approve_account_variant(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    owner: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let confidential_token_account =
+        get_associated_token_address_with_program_id(owner, &confidential_mint, &token_2022::ID);
+
+    let ix = Instruction {
+        accounts: ApproveAccount {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            confidential_token_account,
+            wrapper_authority,
+            admin: signer.pubkey(),
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::ApproveAccount {}.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn verify_backing(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
+
+    let ix = Instruction {
+        accounts: VerifyBacking {
+            token_mint: *token_mint,
+            token_vault,
+            confidential_mint,
+            wrapper_config,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::VerifyBacking {}.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn initialize_global(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    upgrade_authority: &Keypair,
+    admin: &Pubkey,
+    guardian: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: InitializeGlobal {
+            global_config,
+            program: ctw_token::ID,
+            program_data: derive_program_data(),
+            upgrade_authority: upgrade_authority.pubkey(),
+            payer: signer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::InitializeGlobal {
+            admin: *admin,
+            guardian: *guardian,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, upgrade_authority],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_paused(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    authority: &Keypair,
+    token_mint: Option<&Pubkey>,
+    paused: bool,
+) -> Result<(), BanksClientError> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: SetPaused {
+            global_config,
+            wrapper_config: token_mint.map(|token_mint| derive_wrapper_config(token_mint, 0).0),
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetPaused { paused }.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, authority],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn transfer_admin(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    new_admin: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateGlobalConfig {
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::TransferAdmin {
+            new_admin: *new_admin,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn accept_admin(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    pending_admin: &Keypair,
+) -> Result<(), BanksClientError> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: AcceptAdmin {
+            global_config,
+            pending_admin: pending_admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::AcceptAdmin {}.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, pending_admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_guardian(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    guardian: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateGlobalConfig {
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetGuardian {
+            guardian: *guardian,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_default_policy(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    default_wrapper_admin: Option<Pubkey>,
+    default_paused: bool,
+) -> Result<(), BanksClientError> {
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateGlobalConfig {
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetDefaultPolicy {
+            default_wrapper_admin,
+            default_paused,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_fees(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    token_mint: &Pubkey,
+    wrap_fee_bps: u16,
+    unwrap_fee_bps: u16,
+) -> Result<(), BanksClientError> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateWrapperConfig {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetFees {
+            wrap_fee_bps,
+            unwrap_fee_bps,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_limits(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    token_mint: &Pubkey,
+    max_supply: Option<u64>,
+    max_wrap_per_tx: Option<u64>,
+    min_wrap_amount: u64,
+) -> Result<(), BanksClientError> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateWrapperConfig {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetLimits {
+            max_supply,
+            max_wrap_per_tx,
+            min_wrap_amount,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_unwrap_limit(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    authority: &Keypair,
+    token_mint: &Pubkey,
+    unwrap_limit: Option<u64>,
+    unwrap_window_slots: u64,
+) -> Result<(), BanksClientError> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: SetUnwrapLimit {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetUnwrapLimit {
+            unwrap_limit,
+            unwrap_window_slots,
+        }
+        .data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, authority],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn withdraw_fees(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let transfer_hook_accounts = get_transfer_hook_accounts(
+        banks_client,
+        &token_program,
+        &fee_vault,
+        token_mint,
+        destination,
+        &wrapper_authority,
+        amount,
+    )
+    .await;
+
+    let ix = Instruction {
+        accounts: WithdrawFees {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            fee_vault,
+            destination: *destination,
+            wrapper_authority,
+            admin: admin.pubkey(),
+            token_program,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::WithdrawFees { amount }.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_treasury(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    token_mint: &Pubkey,
+    treasury: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+
+    let ix = Instruction {
+        accounts: UpdateWrapperConfig {
+            token_mint: *token_mint,
+            wrapper_config,
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SetTreasury { treasury }.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn sweep_surplus(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    treasury: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
+    let transfer_hook_accounts = get_transfer_hook_accounts(
+        banks_client,
+        &token_program,
+        &token_vault,
+        token_mint,
+        treasury,
+        &wrapper_authority,
+        0,
+    )
+    .await;
+
+    let ix = Instruction {
+        accounts: SweepSurplus {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            token_vault,
+            treasury: *treasury,
+            wrapper_authority,
+            token_program,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SweepSurplus {}.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn migrate_authority(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (program_authority, _) = derive_authority();
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let WrapperConfig {
+        token_vault: legacy_token_vault,
+        admin,
+        ..
+    } = get_wrapper_config(banks_client, token_mint).await;
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
+    let amount = get_token_account_amount(banks_client, &legacy_token_vault).await;
+    let transfer_hook_accounts = get_transfer_hook_accounts(
+        banks_client,
+        &token_program,
+        &legacy_token_vault,
+        token_mint,
+        &token_vault,
+        &program_authority,
+        amount,
+    )
+    .await;
+
+    let ix = Instruction {
+        accounts: MigrateAuthority {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            legacy_token_vault,
+            token_vault,
+            fee_vault,
+            program_authority,
+            wrapper_authority,
+            admin,
+            payer: signer.pubkey(),
+            token_program,
+            associated_token_program: associated_token::ID,
+            token_extensions_program: token_2022::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::MigrateAuthority {}.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Rewrites an initialized wrapper as it would be had it been created under the authority formerly shared
+/// by every wrapper, with its vault owned by that authority and the wrapper authority holding nothing.
+async fn revert_to_program_authority(test: &mut ProgramTestContext, token_mint: &Pubkey) {
+    let (program_authority, program_authority_bump) = derive_authority();
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(&mut test.banks_client, token_mint)
+        .await
+        .unwrap();
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
+    let legacy_token_vault = derive_legacy_token_vault(token_mint, &token_program);
+
+    let mut account = test
+        .banks_client
+        .get_account(confidential_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    {
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack(&mut account.data).unwrap();
+        mint.base.mint_authority = COption::Some(program_authority);
+        if mint.base.freeze_authority.is_some() {
+            mint.base.freeze_authority = COption::Some(program_authority);
+        }
+        mint.pack_base();
+        mint.get_extension_mut::<ConfidentialTransferMint>()
+            .unwrap()
+            .authority = Some(program_authority).try_into().unwrap();
+    }
+    test.set_account(&confidential_mint, &account.into());
+
+    // The vault moves to the Associated Token Account of the shared authority
+    for (source, destination) in [(token_vault, legacy_token_vault), (fee_vault, fee_vault)] {
+        let mut account = test
+            .banks_client
+            .get_account(source)
+            .await
+            .unwrap()
+            .unwrap();
+        {
+            let mut token_account =
+                StateWithExtensionsMut::<Account>::unpack(&mut account.data).unwrap();
+            token_account.base.owner = program_authority;
+            token_account.pack_base();
+        }
+        test.set_account(&source, &AccountSharedData::default());
+        test.set_account(&destination, &account.into());
+    }
+
+    let mut account = test
+        .banks_client
+        .get_account(wrapper_config)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut state = WrapperConfig::try_deserialize(&mut account.data.as_slice()).unwrap();
+    state.token_vault = legacy_token_vault;
+    state.confidential_transfer_authority = program_authority;
+    state.authority_bump = program_authority_bump;
+    state
+        .try_serialize(&mut account.data.as_mut_slice())
+        .unwrap();
+    test.set_account(&wrapper_config, &account.into());
+}
+
+/// Rewrites an initialized wrapper as it would be had it been created before the Wrapper Config existed,
+/// with the freeze authority of its SPL Token Mint copied onto its Confidential Wrapped Token Mint.
+async fn revert_to_legacy_wrapper(test: &mut ProgramTestContext, token_mint: &Pubkey) {
+    revert_to_program_authority(test, token_mint).await;
+
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_freeze_authority =
+        get_mint_freeze_authority(&mut test.banks_client, token_mint).await;
+
+    let mut account = test
+        .banks_client
+        .get_account(confidential_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    {
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack(&mut account.data).unwrap();
+        mint.base.freeze_authority = token_freeze_authority.into();
+        mint.pack_base();
+    }
+    test.set_account(&confidential_mint, &account.into());
+
+    for address in [wrapper_config, fee_vault] {
+        test.set_account(&address, &AccountSharedData::default());
+    }
+}
+
+async fn adopt_legacy_wrapper(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (program_authority, _) = derive_authority();
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_legacy_token_vault(token_mint, &token_program);
+
+    let ix = Instruction {
+        accounts: AdoptLegacyWrapper {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            token_vault,
+            fee_vault,
+            program_authority,
+            payer: signer.pubkey(),
+            token_program,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::AdoptLegacyWrapper {}.data(),
+    };
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Transfers lamports from the signer, such as to fund another keypair acting on its own.
+async fn fund_account(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    recipient: &Pubkey,
+    lamports: u64,
+) -> Result<(), BanksClientError> {
+    let ix = transfer(&signer.pubkey(), recipient, lamports);
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Creates the vault of the given mint ahead of the program, as anyone may.
+async fn create_token_vault(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+
+    let ix = create_associated_token_account(
+        &signer.pubkey(),
+        &wrapper_authority,
+        token_mint,
+        &token_program,
+    );
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
     }
-    .pack_into_slice(&mut account.data);
-    test.add_account(native_mint::id(), account);
-
-    let context = test.start_with_context().await;
-    context
 }
 
-#[tokio::test]
-async fn end_to_end() {
-    let mut test = start_new_program_test().await;
-
-    initialize(&mut test.banks_client, &test.payer, &native_mint::id())
-        .await
-        .unwrap();
-
-    println!("OK");
+/// Transfers tokens from the signer's associated token account, such as to send them straight to a vault.
+async fn transfer_tokens(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_account =
+        get_associated_token_address_with_program_id(&signer.pubkey(), token_mint, &token_program);
+    let account = banks_client.get_account(*token_mint).await?.unwrap();
+    let decimals = StateWithExtensions::<Mint>::unpack(&account.data)
+        .unwrap()
+        .base
+        .decimals;
 
-    create_and_configure_confidential_token_account(
-        &mut test.banks_client,
-        &test.payer,
-        &native_mint::id(),
+    let ix = transfer_checked(
+        &token_program,
+        &token_account,
+        token_mint,
+        destination,
+        &signer.pubkey(),
+        &[],
+        amount,
+        decimals,
     )
-    .await
     .unwrap();
 
-    println!("OK");
-
-    let amount = sol_to_lamports(1.0);
+    println!("Submitting transaction...");
 
-    wrap(
-        &mut test.banks_client,
-        &test.payer,
-        &native_mint::id(),
-        amount,
-    )
-    .await
-    .unwrap();
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
 
-    println!("OK");
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
 
-    post_wrap(
-        &mut test.banks_client,
-        &test.payer,
-        &native_mint::id(),
-        amount,
-    )
-    .await
-    .unwrap();
+async fn close_wrapper(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    admin: &Keypair,
+    token_mint: &Pubkey,
+    destination: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
 
-    println!("OK");
+    let ix = Instruction {
+        accounts: CloseWrapper {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            token_vault,
+            fee_vault,
+            wrapper_authority,
+            admin: admin.pubkey(),
+            destination: *destination,
+            token_program,
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::CloseWrapper {}.data(),
+    };
 
-    withdraw_and_verify(
-        &mut test.banks_client,
-        &test.payer,
-        &native_mint::id(),
-        amount,
-    )
-    .await
-    .unwrap();
+    println!("Submitting transaction...");
 
-    println!("OK");
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer, admin],
+        latest_blockhash,
+    );
 
-    unwrap(
-        &mut test.banks_client,
-        &test.payer,
-        &native_mint::id(),
-        amount,
-    )
-    .await
-    .unwrap();
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
 }
 
-async fn initialize(
+async fn sync_freeze_authority(
     banks_client: &mut BanksClient,
     signer: &Keypair,
     token_mint: &Pubkey,
 ) -> Result<(), BanksClientError> {
-    let elgamal_keypair = ElGamalKeypair::new_from_signer(signer, "auditor".as_ref()).unwrap();
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
 
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
-    let (program_authority, _) = derive_authority();
+    let ix = Instruction {
+        accounts: SyncFreezeAuthority {
+            token_mint: *token_mint,
+            confidential_mint,
+            wrapper_config,
+            wrapper_authority,
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::SyncFreezeAuthority {}.data(),
+    };
 
-    println!(
-        "Creating Confidential Wrapped Token Mint: {}",
-        confidential_mint
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &vec![ix],
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
     );
 
-    let token_vault = get_associated_token_address(&program_authority, token_mint);
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn set_account_frozen(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    freeze_authority: &Keypair,
+    token_mint: &Pubkey,
+    owner: &Pubkey,
+    freeze: bool,
+) -> Result<(), BanksClientError> {
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let confidential_token_account =
+        get_associated_token_address_with_program_id(owner, &confidential_mint, &token_2022::ID);
 
     let ix = Instruction {
-        accounts: Initialize {
+        accounts: FreezeConfidentialAccount {
             token_mint: *token_mint,
-            program_authority,
             confidential_mint,
-            token_vault,
-            payer: signer.pubkey(),
-            token_program: spl_token::ID,
-            associated_token_program: associated_token::ID,
+            wrapper_config,
+            confidential_token_account,
+            wrapper_authority,
+            freeze_authority: freeze_authority.pubkey(),
             token_extensions_program: token_2022::ID,
-            system_program: system_program::ID,
         }
         .to_account_metas(None),
         program_id: ctw_token::ID,
-        data: ctw_token::instruction::Initialize {
-            auditor_pubkey: elgamal_keypair.pubkey().to_bytes(),
-        }
-        .data(),
+        data: if freeze {
+            ctw_token::instruction::FreezeAccount {}.data()
+        } else {
+            ctw_token::instruction::ThawAccount {}.data()
+        },
     };
 
     println!("Submitting transaction...");
@@ -196,7 +5766,7 @@ async fn initialize(
     let tx = Transaction::new_signed_with_payer(
         &vec![ix],
         Some(&signer.pubkey()),
-        &[signer],
+        &[signer, freeze_authority],
         latest_blockhash,
     );
 
@@ -214,9 +5784,13 @@ async fn wrap(
 ) -> Result<(), BanksClientError> {
     println!("\nWrapping into Confidential Transfer Wrapped Token..");
 
-    let (program_authority, _) = derive_authority();
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
-    let token_vault = get_associated_token_address(&program_authority, token_mint);
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, 0);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, 0);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, 0);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, 0, &token_program);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -252,13 +5826,16 @@ async fn wrap(
                     accounts: Wrap {
                         token_mint: *token_mint,
                         token_account,
-                        program_authority,
+                        wrapper_authority,
                         confidential_mint,
+                        wrapper_config,
+                        global_config,
+                        fee_vault,
                         confidential_token_account,
                         token_vault,
                         authority: signer.pubkey(),
                         payer: signer.pubkey(),
-                        token_program: spl_token::ID,
+                        token_program,
                         token_extensions_program: token_2022::ID,
                     }
                     .to_account_metas(None),
@@ -276,23 +5853,55 @@ async fn wrap(
             ],
         )
     } else {
-        let token_account = get_associated_token_address(&signer.pubkey(), token_mint);
+        let token_account = get_associated_token_address_with_program_id(
+            &signer.pubkey(),
+            token_mint,
+            &token_program,
+        );
+        let transfer_accounts = get_transfer_hook_accounts(
+            banks_client,
+            &token_program,
+            &token_account,
+            token_mint,
+            &token_vault,
+            &signer.pubkey(),
+            amount,
+        )
+        .await;
+        let fee_transfer_accounts = get_transfer_hook_accounts(
+            banks_client,
+            &token_program,
+            &token_vault,
+            token_mint,
+            &fee_vault,
+            &wrapper_authority,
+            amount,
+        )
+        .await;
+        let transfer_hook_accounts =
+            merge_account_metas([transfer_accounts, fee_transfer_accounts]);
         (
             None,
             vec![Instruction {
                 accounts: Wrap {
                     token_mint: *token_mint,
                     token_account,
-                    program_authority,
+                    wrapper_authority,
                     confidential_mint,
+                    wrapper_config,
+                    global_config,
+                    fee_vault,
                     confidential_token_account,
                     token_vault,
                     authority: signer.pubkey(),
                     payer: signer.pubkey(),
-                    token_program: spl_token::ID,
+                    token_program,
                     token_extensions_program: token_2022::ID,
                 }
-                .to_account_metas(None),
+                .to_account_metas(None)
+                .into_iter()
+                .chain(transfer_hook_accounts)
+                .collect(),
                 program_id: ctw_token::ID,
                 data: ctw_token::instruction::Wrap { amount }.data(),
             }],
@@ -321,17 +5930,147 @@ async fn wrap(
     }
 }
 
+/// Wraps from the signer's associated token account into the associated token account of the recipient,
+/// creating the latter if it does not exist yet.
+async fn wrap_to(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    wrap_to_variant(banks_client, signer, token_mint, 0, recipient, amount).await
+}
+
+async fn wrap_to_variant(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    recipient: &Pubkey,
+    amount: u64,
+) -> Result<(), BanksClientError> {
+    println!("\nWrapping into the Confidential Transfer Wrapped Token account of another owner..");
+
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, variant);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
+    let token_account =
+        get_associated_token_address_with_program_id(&signer.pubkey(), token_mint, &token_program);
+    let confidential_token_account = get_associated_token_address_with_program_id(
+        recipient,
+        &confidential_mint,
+        &token_2022::ID,
+    );
+    let transfer_accounts = get_transfer_hook_accounts(
+        banks_client,
+        &token_program,
+        &token_account,
+        token_mint,
+        &token_vault,
+        &signer.pubkey(),
+        amount,
+    )
+    .await;
+    let fee_transfer_accounts = get_transfer_hook_accounts(
+        banks_client,
+        &token_program,
+        &token_vault,
+        token_mint,
+        &fee_vault,
+        &wrapper_authority,
+        amount,
+    )
+    .await;
+    let transfer_hook_accounts = merge_account_metas([transfer_accounts, fee_transfer_accounts]);
+
+    let mut ixs = vec![];
+    if banks_client
+        .get_account(confidential_token_account)
+        .await?
+        .is_none()
+    {
+        ixs.push(create_associated_token_account(
+            &signer.pubkey(),
+            recipient,
+            &confidential_mint,
+            &token_2022::ID,
+        ));
+    }
+    ixs.push(Instruction {
+        accounts: Wrap {
+            token_mint: *token_mint,
+            token_account,
+            wrapper_authority,
+            confidential_mint,
+            wrapper_config,
+            global_config,
+            fee_vault,
+            confidential_token_account,
+            token_vault,
+            authority: signer.pubkey(),
+            payer: signer.pubkey(),
+            token_program,
+            token_extensions_program: token_2022::ID,
+        }
+        .to_account_metas(None)
+        .into_iter()
+        .chain(transfer_hook_accounts)
+        .collect(),
+        program_id: ctw_token::ID,
+        data: ctw_token::instruction::WrapTo { amount }.data(),
+    });
+
+    println!("Submitting transaction...");
+
+    let latest_blockhash = match banks_client.get_latest_blockhash().await {
+        Ok(lb) => lb,
+        Err(e) => {
+            return Err(e);
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&signer.pubkey()),
+        &[signer],
+        latest_blockhash,
+    );
+
+    match banks_client.process_transaction(tx).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
 async fn unwrap(
     banks_client: &mut BanksClient,
     signer: &Keypair,
     token_mint: &Pubkey,
     amount: u64,
+) -> Result<(), BanksClientError> {
+    unwrap_variant(banks_client, signer, token_mint, 0, amount).await
+}
+
+async fn unwrap_variant(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
+    amount: u64,
 ) -> Result<(), BanksClientError> {
     println!("\nUnwrapping from Confidential Transfer Wrapped Token..");
 
-    let (program_authority, _) = derive_authority();
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
-    let token_vault = get_associated_token_address(&program_authority, token_mint);
+    let (wrapper_authority, _) = derive_wrapper_authority(token_mint, variant);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
+    let (wrapper_config, _) = derive_wrapper_config(token_mint, variant);
+    let (global_config, _) = derive_global_config();
+    let (fee_vault, _) = derive_fee_vault(token_mint, variant);
+    let token_program = get_token_program(banks_client, token_mint).await?;
+    let token_vault = derive_token_vault(token_mint, variant, &token_program);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -367,13 +6106,16 @@ async fn unwrap(
                     accounts: Unwrap {
                         token_mint: *token_mint,
                         token_account,
-                        program_authority,
+                        wrapper_authority,
                         confidential_mint,
+                        wrapper_config,
+                        global_config,
+                        fee_vault,
                         confidential_token_account,
                         token_vault,
                         authority: signer.pubkey(),
                         payer: signer.pubkey(),
-                        token_program: spl_token::ID,
+                        token_program,
                         token_extensions_program: token_2022::ID,
                     }
                     .to_account_metas(None),
@@ -391,23 +6133,55 @@ async fn unwrap(
             ],
         )
     } else {
-        let token_account = get_associated_token_address(&signer.pubkey(), token_mint);
+        let token_account = get_associated_token_address_with_program_id(
+            &signer.pubkey(),
+            token_mint,
+            &token_program,
+        );
+        let transfer_accounts = get_transfer_hook_accounts(
+            banks_client,
+            &token_program,
+            &token_vault,
+            token_mint,
+            &token_account,
+            &wrapper_authority,
+            amount,
+        )
+        .await;
+        let fee_transfer_accounts = get_transfer_hook_accounts(
+            banks_client,
+            &token_program,
+            &token_vault,
+            token_mint,
+            &fee_vault,
+            &wrapper_authority,
+            amount,
+        )
+        .await;
+        let transfer_hook_accounts =
+            merge_account_metas([transfer_accounts, fee_transfer_accounts]);
         (
             None,
             vec![Instruction {
                 accounts: Unwrap {
                     token_mint: *token_mint,
                     token_account,
-                    program_authority,
+                    wrapper_authority,
                     confidential_mint,
+                    wrapper_config,
+                    global_config,
+                    fee_vault,
                     confidential_token_account,
                     token_vault,
                     authority: signer.pubkey(),
                     payer: signer.pubkey(),
-                    token_program: spl_token::ID,
+                    token_program,
                     token_extensions_program: token_2022::ID,
                 }
-                .to_account_metas(None),
+                .to_account_metas(None)
+                .into_iter()
+                .chain(transfer_hook_accounts)
+                .collect(),
                 program_id: ctw_token::ID,
                 data: ctw_token::instruction::Unwrap { amount }.data(),
             }],
@@ -439,10 +6213,20 @@ async fn create_and_configure_confidential_token_account(
     banks_client: &mut BanksClient,
     signer: &Keypair,
     token_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    create_and_configure_confidential_token_account_variant(banks_client, signer, token_mint, 0)
+        .await
+}
+
+async fn create_and_configure_confidential_token_account_variant(
+    banks_client: &mut BanksClient,
+    signer: &Keypair,
+    token_mint: &Pubkey,
+    variant: u16,
 ) -> Result<(), BanksClientError> {
     println!("\nCreating and configuring Token Account with Confidential Transfers extension..");
 
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, variant);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -527,7 +6311,7 @@ async fn withdraw_and_verify(
 ) -> Result<(), BanksClientError> {
     println!("\nWithdrawing from encrypted balance and verifying..");
 
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -637,7 +6421,7 @@ async fn post_wrap(
 ) -> Result<(), BanksClientError> {
     println!("\nApplying pending balance..");
 
-    let (confidential_mint, _) = derive_confidential_mint(token_mint);
+    let (confidential_mint, _) = derive_confidential_mint(token_mint, 0);
     let confidential_token_account = get_associated_token_address_with_program_id(
         &signer.pubkey(),
         &confidential_mint,
@@ -1,4 +1,4 @@
-import { Program } from "@coral-xyz/anchor";
+import { BorshCoder, EventParser, Program } from "@coral-xyz/anchor";
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { CtwToken, IDL } from "./program";
 
@@ -6,10 +6,32 @@ export const PROGRAM_ID = new PublicKey(
   "cwTokjpVjxBeytEXomNe5B38EesYsNsXCm3JZC6tmvB"
 );
 
+/**
+ * The seed distinguishing the variants of the wrapper of an SPL Token Mint, empty for the first variant so that
+ * the wrappers created before variants existed keep their addresses.
+ */
+const variantSeed = (variant: number) => {
+  return variant === 0
+    ? Buffer.alloc(0)
+    : Buffer.from([variant & 0xff, variant >> 8]);
+};
+
 const AUTHORITY_SEED = "AUTHORITY";
 
-export const findAuthorityPda = (tokenMint: PublicKey) => {
-  PublicKey.findProgramAddressSync(
+export const findAuthorityPda = (tokenMint: PublicKey, variant = 0) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      tokenMint.toBuffer(),
+      Buffer.from(AUTHORITY_SEED, "utf-8"),
+      variantSeed(variant),
+    ],
+    PROGRAM_ID
+  );
+};
+
+/** Finds the authority formerly shared by every wrapper, only needed to migrate them. */
+export const findProgramAuthorityPda = () => {
+  return PublicKey.findProgramAddressSync(
     [Buffer.from(AUTHORITY_SEED, "utf-8")],
     PROGRAM_ID
   );
@@ -17,35 +39,97 @@ export const findAuthorityPda = (tokenMint: PublicKey) => {
 
 const MINT_SEED = "MINT";
 
-export const findConfidentialMintPda = (tokenMint: PublicKey) => {
-  PublicKey.findProgramAddressSync(
-    [tokenMint.toBuffer(), Buffer.from(MINT_SEED, "utf-8")],
+export const findConfidentialMintPda = (tokenMint: PublicKey, variant = 0) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      tokenMint.toBuffer(),
+      Buffer.from(MINT_SEED, "utf-8"),
+      variantSeed(variant),
+    ],
+    PROGRAM_ID
+  );
+};
+
+const WRAPPER_SEED = "WRAPPER";
+
+export const findWrapperConfigPda = (tokenMint: PublicKey, variant = 0) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      tokenMint.toBuffer(),
+      Buffer.from(WRAPPER_SEED, "utf-8"),
+      variantSeed(variant),
+    ],
+    PROGRAM_ID
+  );
+};
+
+const GLOBAL_SEED = "GLOBAL";
+
+export const findGlobalConfigPda = () => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(GLOBAL_SEED, "utf-8")],
+    PROGRAM_ID
+  );
+};
+
+const FEE_VAULT_SEED = "FEE_VAULT";
+
+export const findFeeVaultPda = (tokenMint: PublicKey, variant = 0) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      tokenMint.toBuffer(),
+      Buffer.from(FEE_VAULT_SEED, "utf-8"),
+      variantSeed(variant),
+    ],
     PROGRAM_ID
   );
 };
 
 const program = new Program<CtwToken>(IDL, PROGRAM_ID);
 
+export type InitializeOptions = {
+  autoApproveNewAccounts: boolean;
+  confidentialTransferAuthority: PublicKey | null;
+  auditorElgamalPubkey: number[] | null;
+  variant: number;
+  admin: PublicKey | null;
+};
+
+const eventParser = new EventParser(PROGRAM_ID, new BorshCoder(IDL));
+
+/** Decodes the events emitted by the program from the log messages of a transaction. */
+export const parseEvents = (logs: string[]) => {
+  return [...eventParser.parseLogs(logs)];
+};
+
 export const initialize = async (
   tokenMint: PublicKey,
   confidentialMint: PublicKey,
-  programAuthority: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  wrapperAuthority: PublicKey,
   tokenVault: PublicKey,
+  feeVault: PublicKey,
   payer: PublicKey,
+  auditorAuthority: PublicKey | null,
   tokenProgram: PublicKey,
   associatedTokenProgram: PublicKey,
   tokenExtensionsProgram: PublicKey,
   systemProgram: PublicKey,
-  auditorPublicKey: Buffer | Uint8Array
+  options: InitializeOptions
 ): Promise<TransactionInstruction> => {
   return await program.methods
-    .initialize([...auditorPublicKey])
+    .initialize(options)
     .accountsStrict({
       tokenMint,
       confidentialMint,
-      programAuthority,
+      wrapperConfig,
+      globalConfig,
+      wrapperAuthority,
       tokenVault,
+      feeVault,
       payer,
+      auditorAuthority,
       tokenProgram,
       associatedTokenProgram,
       tokenExtensionsProgram,
@@ -59,8 +143,11 @@ export const wrap = async (
   tokenAccount: PublicKey,
   tokenVault: PublicKey,
   confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  feeVault: PublicKey,
   confidentialTokenAccount: PublicKey,
-  programAuthority: PublicKey,
+  wrapperAuthority: PublicKey,
   authority: PublicKey,
   payer: PublicKey,
   tokenProgram: PublicKey,
@@ -74,8 +161,47 @@ export const wrap = async (
       tokenAccount,
       tokenVault,
       confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      feeVault,
       confidentialTokenAccount,
-      programAuthority,
+      wrapperAuthority,
+      authority,
+      payer,
+      tokenProgram,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const wrapTo = async (
+  tokenMint: PublicKey,
+  tokenAccount: PublicKey,
+  tokenVault: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  feeVault: PublicKey,
+  confidentialTokenAccount: PublicKey,
+  wrapperAuthority: PublicKey,
+  authority: PublicKey,
+  payer: PublicKey,
+  tokenProgram: PublicKey,
+  tokenExtensionsProgram: PublicKey,
+  amount: number
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .wrapTo(amount)
+    .accountsStrict({
+      tokenMint,
+      tokenAccount,
+      tokenVault,
+      confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      feeVault,
+      confidentialTokenAccount,
+      wrapperAuthority,
       authority,
       payer,
       tokenProgram,
@@ -89,8 +215,11 @@ export const unwrap = async (
   tokenAccount: PublicKey,
   tokenVault: PublicKey,
   confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  feeVault: PublicKey,
   confidentialTokenAccount: PublicKey,
-  programAuthority: PublicKey,
+  wrapperAuthority: PublicKey,
   authority: PublicKey,
   payer: PublicKey,
   tokenProgram: PublicKey,
@@ -104,8 +233,11 @@ export const unwrap = async (
       tokenAccount,
       tokenVault,
       confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      feeVault,
       confidentialTokenAccount,
-      programAuthority,
+      wrapperAuthority,
       authority,
       payer,
       tokenProgram,
@@ -113,3 +245,479 @@ export const unwrap = async (
     })
     .instruction();
 };
+
+export const updateConfidentialMint = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  wrapperAuthority: PublicKey,
+  admin: PublicKey,
+  tokenExtensionsProgram: PublicKey,
+  autoApproveNewAccounts: boolean,
+  auditorPublicKey: Buffer | Uint8Array | null
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .updateConfidentialMint(
+      autoApproveNewAccounts,
+      auditorPublicKey ? [...auditorPublicKey] : null
+    )
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      wrapperAuthority,
+      admin,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const rotateAuditor = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  wrapperAuthority: PublicKey,
+  admin: PublicKey,
+  tokenExtensionsProgram: PublicKey,
+  auditorPublicKey: Buffer | Uint8Array
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .rotateAuditor([...auditorPublicKey])
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      wrapperAuthority,
+      admin,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const approveAccount = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  confidentialTokenAccount: PublicKey,
+  wrapperAuthority: PublicKey,
+  admin: PublicKey,
+  tokenExtensionsProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .approveAccount()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      confidentialTokenAccount,
+      wrapperAuthority,
+      admin,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const verifyBacking = async (
+  tokenMint: PublicKey,
+  tokenVault: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .verifyBacking()
+    .accountsStrict({
+      tokenMint,
+      tokenVault,
+      confidentialMint,
+      wrapperConfig,
+    })
+    .instruction();
+};
+
+export const syncFreezeAuthority = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  wrapperAuthority: PublicKey,
+  tokenExtensionsProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .syncFreezeAuthority()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      wrapperAuthority,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const freezeAccount = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  confidentialTokenAccount: PublicKey,
+  wrapperAuthority: PublicKey,
+  freezeAuthority: PublicKey,
+  tokenExtensionsProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .freezeAccount()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      confidentialTokenAccount,
+      wrapperAuthority,
+      freezeAuthority,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const thawAccount = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  confidentialTokenAccount: PublicKey,
+  wrapperAuthority: PublicKey,
+  freezeAuthority: PublicKey,
+  tokenExtensionsProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .thawAccount()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      confidentialTokenAccount,
+      wrapperAuthority,
+      freezeAuthority,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const initializeGlobal = async (
+  globalConfig: PublicKey,
+  programData: PublicKey,
+  upgradeAuthority: PublicKey,
+  payer: PublicKey,
+  systemProgram: PublicKey,
+  admin: PublicKey,
+  guardian: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .initializeGlobal(admin, guardian)
+    .accountsStrict({
+      globalConfig,
+      program: PROGRAM_ID,
+      programData,
+      upgradeAuthority,
+      payer,
+      systemProgram,
+    })
+    .instruction();
+};
+
+/** Pauses or unpauses the given wrapper, or every wrapper if `wrapperConfig` is `null`. */
+export const setPaused = async (
+  globalConfig: PublicKey,
+  wrapperConfig: PublicKey | null,
+  authority: PublicKey,
+  paused: boolean
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setPaused(paused)
+    .accountsStrict({
+      globalConfig,
+      wrapperConfig,
+      authority,
+    })
+    .instruction();
+};
+
+export const transferAdmin = async (
+  globalConfig: PublicKey,
+  admin: PublicKey,
+  newAdmin: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .transferAdmin(newAdmin)
+    .accountsStrict({
+      globalConfig,
+      admin,
+    })
+    .instruction();
+};
+
+export const acceptAdmin = async (
+  globalConfig: PublicKey,
+  pendingAdmin: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .acceptAdmin()
+    .accountsStrict({
+      globalConfig,
+      pendingAdmin,
+    })
+    .instruction();
+};
+
+export const setGuardian = async (
+  globalConfig: PublicKey,
+  admin: PublicKey,
+  guardian: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setGuardian(guardian)
+    .accountsStrict({
+      globalConfig,
+      admin,
+    })
+    .instruction();
+};
+
+export const setDefaultPolicy = async (
+  globalConfig: PublicKey,
+  admin: PublicKey,
+  defaultWrapperAdmin: PublicKey | null,
+  defaultPaused: boolean
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setDefaultPolicy(defaultWrapperAdmin, defaultPaused)
+    .accountsStrict({
+      globalConfig,
+      admin,
+    })
+    .instruction();
+};
+
+export const setFees = async (
+  tokenMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  admin: PublicKey,
+  wrapFeeBps: number,
+  unwrapFeeBps: number
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setFees(wrapFeeBps, unwrapFeeBps)
+    .accountsStrict({
+      tokenMint,
+      wrapperConfig,
+      globalConfig,
+      admin,
+    })
+    .instruction();
+};
+
+export const setLimits = async (
+  tokenMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  admin: PublicKey,
+  maxSupply: number | null,
+  maxWrapPerTx: number | null,
+  minWrapAmount: number
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setLimits(maxSupply, maxWrapPerTx, minWrapAmount)
+    .accountsStrict({
+      tokenMint,
+      wrapperConfig,
+      globalConfig,
+      admin,
+    })
+    .instruction();
+};
+
+export const setUnwrapLimit = async (
+  tokenMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  authority: PublicKey,
+  unwrapLimit: number | null,
+  unwrapWindowSlots: number
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setUnwrapLimit(unwrapLimit, unwrapWindowSlots)
+    .accountsStrict({
+      tokenMint,
+      wrapperConfig,
+      globalConfig,
+      authority,
+    })
+    .instruction();
+};
+
+export const withdrawFees = async (
+  tokenMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  feeVault: PublicKey,
+  destination: PublicKey,
+  wrapperAuthority: PublicKey,
+  admin: PublicKey,
+  tokenProgram: PublicKey,
+  amount: number
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .withdrawFees(amount)
+    .accountsStrict({
+      tokenMint,
+      wrapperConfig,
+      globalConfig,
+      feeVault,
+      destination,
+      wrapperAuthority,
+      admin,
+      tokenProgram,
+    })
+    .instruction();
+};
+
+export const setTreasury = async (
+  tokenMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  admin: PublicKey,
+  treasury: PublicKey | null
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .setTreasury(treasury)
+    .accountsStrict({
+      tokenMint,
+      wrapperConfig,
+      globalConfig,
+      admin,
+    })
+    .instruction();
+};
+
+export const sweepSurplus = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  tokenVault: PublicKey,
+  treasury: PublicKey,
+  wrapperAuthority: PublicKey,
+  tokenProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .sweepSurplus()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      tokenVault,
+      treasury,
+      wrapperAuthority,
+      tokenProgram,
+    })
+    .instruction();
+};
+
+export const closeWrapper = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  tokenVault: PublicKey,
+  feeVault: PublicKey,
+  wrapperAuthority: PublicKey,
+  admin: PublicKey,
+  destination: PublicKey,
+  tokenProgram: PublicKey,
+  tokenExtensionsProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .closeWrapper()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      tokenVault,
+      feeVault,
+      wrapperAuthority,
+      admin,
+      destination,
+      tokenProgram,
+      tokenExtensionsProgram,
+    })
+    .instruction();
+};
+
+export const migrateAuthority = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  legacyTokenVault: PublicKey,
+  tokenVault: PublicKey,
+  feeVault: PublicKey,
+  programAuthority: PublicKey,
+  wrapperAuthority: PublicKey,
+  admin: PublicKey,
+  payer: PublicKey,
+  tokenProgram: PublicKey,
+  associatedTokenProgram: PublicKey,
+  tokenExtensionsProgram: PublicKey,
+  systemProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .migrateAuthority()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      legacyTokenVault,
+      tokenVault,
+      feeVault,
+      programAuthority,
+      wrapperAuthority,
+      admin,
+      payer,
+      tokenProgram,
+      associatedTokenProgram,
+      tokenExtensionsProgram,
+      systemProgram,
+    })
+    .instruction();
+};
+
+export const adoptLegacyWrapper = async (
+  tokenMint: PublicKey,
+  confidentialMint: PublicKey,
+  wrapperConfig: PublicKey,
+  globalConfig: PublicKey,
+  tokenVault: PublicKey,
+  feeVault: PublicKey,
+  programAuthority: PublicKey,
+  payer: PublicKey,
+  tokenProgram: PublicKey,
+  systemProgram: PublicKey
+): Promise<TransactionInstruction> => {
+  return await program.methods
+    .adoptLegacyWrapper()
+    .accountsStrict({
+      tokenMint,
+      confidentialMint,
+      wrapperConfig,
+      globalConfig,
+      tokenVault,
+      feeVault,
+      programAuthority,
+      payer,
+      tokenProgram,
+      systemProgram,
+    })
+    .instruction();
+};
@@ -12,8 +12,10 @@ export type CtwToken = {
         "",
         "# Notes",
         "",
-        "This implementation does not require any new CT-enabled Token Accounts to be approved and",
-        "are 1:1 equivalents of the SPL Token."
+        "The approval policy, the confidential transfer authority, the auditor and the admin are configured through",
+        "the given [`InitializeOptions`], and may only differ from the defaults with the co-signature of the mint",
+        "authority of the SPL Token Mint or the admin of the program. Confidential Wrapped Tokens are 1:1 equivalents",
+        "of the SPL Token."
       ];
       accounts: [
         {
@@ -31,16 +33,38 @@ export type CtwToken = {
           docs: ["The SPL Token Extensions Mint."];
         },
         {
-          name: "programAuthority";
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "wrapperAuthority";
           isMut: false;
           isSigner: false;
-          docs: ["The authority of the Confidential Wrapper Token Program."];
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
         },
         {
           name: "tokenVault";
           isMut: true;
           isSigner: false;
-          docs: ["The token vault."];
+          docs: [
+            "The token vault, which anyone may have created beforehand as it is an Associated Token Account."
+          ];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
         },
         {
           name: "payer";
@@ -48,11 +72,23 @@ export type CtwToken = {
           isSigner: true;
           docs: ["The fee and rent payer."];
         },
+        {
+          name: "auditorAuthority";
+          isMut: false;
+          isSigner: true;
+          isOptional: true;
+          docs: [
+            "The mint authority of the SPL Token Mint or the admin of the program, required to choose an auditor,",
+            "a confidential transfer authority other than the wrapper authority or the admin, or to require approval."
+          ];
+        },
         {
           name: "tokenProgram";
           isMut: false;
           isSigner: false;
-          docs: ["The Token Program."];
+          docs: [
+            "The Token Program which owns the SPL Token Mint, either the SPL Token Program or the Token Extensions Program."
+          ];
         },
         {
           name: "associatedTokenProgram";
@@ -75,9 +111,9 @@ export type CtwToken = {
       ];
       args: [
         {
-          name: "auditorPubkey";
+          name: "options";
           type: {
-            array: ["u8", 32];
+            defined: "InitializeOptions";
           };
         }
       ];
@@ -91,10 +127,27 @@ export type CtwToken = {
         "",
         "The integrator is responsible for passing in a TokenAccount for the `confidential_token_account` param",
         "that has already been initialized and for which the [`ConfigureAccount`] as well as, if necessary,",
-        "the [`ApproveAccount`] instructions have been executed.",
+        "the [`ApproveAccount`] instructions have been executed. If the wrapper requires approval of new accounts,",
+        "the admin approves them through [`approve_account`].",
+        "",
+        "After this instruction is called, the integrator is then free to call [`ApplyPendingBalance`]",
+        "in order to roll the token amount into the available balance of the Confidential Token Account.",
+        "",
+        "If the SPL Token Mint charges a transfer fee, only the amount actually received by the vault is minted.",
+        "",
+        "The wrap fee of the wrapper is deducted from the amount received by the vault and moved into the fee vault,",
+        "only the remainder is minted. If the SPL Token Mint charges a transfer fee, moving the fee is charged one too,",
+        "and only what the fee vault received counts towards the total fees of the wrapper.",
+        "",
+        "Frozen SPL Token Accounts cannot be wrapped from.",
         "",
-        "After this instruction is called, the integrator is then free to call [`Deposit`] and [`ApplyPendingBalance`]",
-        "in order to roll the token amount into the available balance of the Confidential Token Account."
+        "The amount must be within the per-transaction limits of the wrapper, and the supply of the",
+        "Confidential Wrapped Token Mint may not exceed its maximum supply once minted.",
+        "",
+        "Fails with [`CtwError::Paused`] while either the wrapper or every wrapper is paused through [`set_paused`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer into the vault",
+        "and for the transfer of the fee into the fee vault must be passed in as remaining accounts."
       ];
       accounts: [
         {
@@ -119,16 +172,36 @@ export type CtwToken = {
           isSigner: false;
           docs: ["The mint of the token being wrapped."];
         },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
+        },
         {
           name: "confidentialTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "programAuthority";
+          name: "wrapperAuthority";
           isMut: false;
           isSigner: false;
-          docs: ["The authority of the Confidential Wrapper Token Program."];
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
         },
         {
           name: "authority";
@@ -163,16 +236,23 @@ export type CtwToken = {
       ];
     },
     {
-      name: "unwrap";
+      name: "wrapTo";
       docs: [
-        "Unwrap the given token amount of a Confidential Wrapped Token back into it's corresponding",
-        "SPL Token Mint.",
+        "Wrap the given token amount of an SPL Token into the Confidential Token Account of another owner, such as",
+        "a company paying out its employees.",
         "",
         "# Notes",
         "",
-        "The integrator is responsible for assuring that the user has enough non-confidential",
-        "balance in order to unwrap and redeem for the underlying token.",
-        "This can be achieved by having the [`Withdraw`] instruction being successfully executed beforehand."
+        "Unlike [`wrap`], the minted tokens are left in the public balance of the recipient's account, as depositing",
+        "them into its confidential balance requires the signature of its owner, who may [`Deposit`] them later on.",
+        "The recipient's account therefore only needs to exist, not to be configured for confidential transfers,",
+        "unless the wrapper requires new accounts to be approved, in which case it fails with",
+        "[`CtwError::AccountNotApproved`] until the account is configured and approved.",
+        "",
+        "The fees, limits and pause of the wrapper apply as they do to [`wrap`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer into the vault",
+        "and for the transfer of the fee into the fee vault must be passed in as remaining accounts."
       ];
       accounts: [
         {
@@ -197,16 +277,36 @@ export type CtwToken = {
           isSigner: false;
           docs: ["The mint of the token being wrapped."];
         },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
+        },
         {
           name: "confidentialTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "programAuthority";
+          name: "wrapperAuthority";
           isMut: false;
           isSigner: false;
-          docs: ["The authority of the Confidential Wrapper Token Program."];
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
         },
         {
           name: "authority";
@@ -239,251 +339,5765 @@ export type CtwToken = {
           type: "u64";
         }
       ];
-    }
-  ];
-};
-
-export const IDL: CtwToken = {
-  version: "0.1.0",
-  name: "ctw_token",
-  instructions: [
+    },
     {
-      name: "initialize",
+      name: "unwrap";
       docs: [
-        "Initialize a Confidential Transfer enabled Token Extensions Mint for an existing SPL Token Mint.",
-        "This Confidential Transfer enabled Token Extensions Mint, or Confidential Wrapped Token Mint,",
-        "effectively represents the same underlying SPL Token but with the ability to use Token Extensions'",
-        "zk-powered confidential transfers which mask the amount being transferred.",
+        "Unwrap the given token amount of a Confidential Wrapped Token back into it's corresponding",
+        "SPL Token Mint.",
         "",
         "# Notes",
         "",
-        "This implementation does not require any new CT-enabled Token Accounts to be approved and",
-        "are 1:1 equivalents of the SPL Token.",
-      ],
+        "The integrator is responsible for assuring that the user has enough non-confidential",
+        "balance in order to unwrap and redeem for the underlying token.",
+        "This can be achieved by having the [`Withdraw`] instruction being successfully executed beforehand.",
+        "",
+        "If the SPL Token Mint charges a transfer fee, it is withheld from the amount received by the user.",
+        "",
+        "The unwrap fee of the wrapper is deducted from the amount sent to the user and moved into the fee vault.",
+        "If the SPL Token Mint charges a transfer fee, moving the fee is charged one too, and only what the fee vault",
+        "received counts towards the total fees of the wrapper.",
+        "",
+        "Frozen SPL Token Accounts cannot be unwrapped into.",
+        "",
+        "The amount, including the fee, counts towards the unwrap limit of the wrapper set through [`set_unwrap_limit`].",
+        "",
+        "Fails with [`CtwError::Paused`] while either the wrapper or every wrapper is paused through [`set_paused`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer out of the vault",
+        "and for the transfer of the fee into the fee vault must be passed in as remaining accounts."
+      ];
       accounts: [
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
-          docs: [
-            "The SPL Token Mint for which we want to create a Confidential Transfers Mint Wrapper.",
-          ],
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
         },
         {
-          name: "confidentialMint",
-          isMut: true,
-          isSigner: false,
-          docs: ["The SPL Token Extensions Mint."],
+          name: "tokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "programAuthority",
-          isMut: false,
-          isSigner: false,
-          docs: ["The authority of the Confidential Wrapper Token Program."],
+          name: "tokenVault";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenVault",
-          isMut: true,
-          isSigner: false,
-          docs: ["The token vault."],
+          name: "confidentialMint";
+          isMut: true;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-          docs: ["The fee and rent payer."],
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Token Program."],
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Associated Token Program."],
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
         },
         {
-          name: "tokenExtensionsProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Token Extensions Program."],
+          name: "confidentialTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The System Program."],
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
         },
-      ],
-      args: [
         {
-          name: "auditorPubkey",
-          type: {
-            array: ["u8", 32],
-          },
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+          docs: ["The authority of the source token account."];
         },
-      ],
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          docs: ["The fee and rent payer."];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Interface."];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Interface."];
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
     },
     {
-      name: "wrap",
+      name: "verifyBacking";
       docs: [
-        "Wrap the given token amount of an SPL Token into an equivalent amount of a Confidential Wrapped Token Mint.",
+        "Verify that the supply of a Confidential Wrapped Token is fully backed by the SPL Tokens held in the vault.",
         "",
         "# Notes",
         "",
-        "The integrator is responsible for passing in a TokenAccount for the `confidential_token_account` param",
-        "that has already been initialized and for which the [`ConfigureAccount`] as well as, if necessary,",
-        "the [`ApproveAccount`] instructions have been executed.",
-        "",
-        "After this instruction is called, the integrator is then free to call [`Deposit`] and [`ApplyPendingBalance`]",
-        "in order to roll the token amount into the available balance of the Confidential Token Account.",
-      ],
+        "This permissionless instruction does not modify any account, it logs the supply and the vault balance",
+        "and fails if the vault holds less than the supply, so it can be called by anyone, such as a keeper."
+      ];
       accounts: [
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
-          docs: ["The mint of the token being wrapped."],
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
         },
         {
-          name: "tokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "tokenVault";
+          isMut: false;
+          isSigner: false;
+          docs: ["The token vault."];
         },
         {
-          name: "tokenVault",
-          isMut: true,
-          isSigner: false,
+          name: "confidentialMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
         },
         {
-          name: "confidentialMint",
-          isMut: true,
-          isSigner: false,
-          docs: ["The mint of the token being wrapped."],
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateConfidentialMint";
+      docs: [
+        "Update whether new CT-enabled Token Accounts must be approved and the auditor of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program, or the mint authority of the SPL Token Mint, may call this",
+        "instruction, and only if the wrapper authority is the confidential transfer authority of the Confidential",
+        "Wrapped Token Mint.",
+        "",
+        "A new auditor is recorded in the [`AuditorPolicy`] of the wrapper along with who chose it, the admin of the",
+        "wrapper having itself been chosen by the mint authority of the SPL Token Mint or the admin of the program."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
         },
         {
-          name: "confidentialTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "confidentialMint";
+          isMut: true;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
         },
         {
-          name: "programAuthority",
-          isMut: false,
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "The admin of the wrapper or of the program, or the mint authority of the SPL Token Mint."
+          ];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [
+        {
+          name: "autoApproveNewAccounts";
+          type: "bool";
+        },
+        {
+          name: "auditorElgamalPubkey";
+          type: {
+            option: {
+              array: ["u8", 32];
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "rotateAuditor";
+      docs: [
+        "Rotate the auditor of a Confidential Wrapped Token Mint, keeping whether new CT-enabled Token Accounts",
+        "must be approved as is.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program, or the mint authority of the SPL Token Mint, may call this",
+        "instruction, and only if the wrapper authority is the confidential transfer authority of the Confidential",
+        "Wrapped Token Mint.",
+        "",
+        "A new auditor is recorded in the [`AuditorPolicy`] of the wrapper along with who chose it, the admin of the",
+        "wrapper having itself been chosen by the mint authority of the SPL Token Mint or the admin of the program.",
+        "",
+        "The previous auditor is recorded in the history of the wrapper."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: true;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "The admin of the wrapper or of the program, or the mint authority of the SPL Token Mint."
+          ];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [
+        {
+          name: "auditorElgamalPubkey";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
+    {
+      name: "approveAccount";
+      docs: [
+        "Approve a CT-enabled Token Account of a Confidential Wrapped Token Mint which requires approval of new accounts.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction, and only if the wrapper authority",
+        "is the confidential transfer authority of the Confidential Wrapped Token Mint.",
+        "",
+        "The Confidential Token Account must have been configured with [`ConfigureAccount`] beforehand."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "confidentialTokenAccount";
+          isMut: true;
+          isSigner: false;
+          docs: ["The Confidential Token Account to approve."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the wrapper or of the program."];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "syncFreezeAuthority";
+      docs: [
+        "Mirror the current freeze authority of the SPL Token Mint onto the Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless. While the SPL Token Mint has a freeze authority, the wrapper authority",
+        "holds the freeze authority of the Confidential Wrapped Token Mint on its behalf, and once the SPL Token Mint's",
+        "freeze authority is revoked, the wrapper authority revokes its own through `SetAuthority`.",
+        "",
+        "Wrappers whose Confidential Wrapped Token Mint has a freeze authority other than the wrapper authority",
+        "can only be synced while it matches the SPL Token Mint's freeze authority. Those whose Confidential Wrapped",
+        "Token Mint's freeze authority was revoked while the SPL Token Mint still has one fail with",
+        "[`CtwError::FreezeAuthorityRevoked`], as `SetAuthority` cannot restore a revoked authority."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: true;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "freezeAccount";
+      docs: [
+        "Freeze a Confidential Token Account of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "Only the current freeze authority of the SPL Token Mint may call this instruction."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "confidentialTokenAccount";
+          isMut: true;
+          isSigner: false;
+          docs: ["The Confidential Token Account to freeze or thaw."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "freezeAuthority";
+          isMut: false;
+          isSigner: true;
+          docs: ["The freeze authority of the SPL Token Mint."];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "thawAccount";
+      docs: [
+        "Thaw a frozen Confidential Token Account of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "Only the current freeze authority of the SPL Token Mint may call this instruction."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "confidentialTokenAccount";
+          isMut: true;
+          isSigner: false;
+          docs: ["The Confidential Token Account to freeze or thaw."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "freezeAuthority";
+          isMut: false;
+          isSigner: true;
+          docs: ["The freeze authority of the SPL Token Mint."];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeGlobal";
+      docs: [
+        "Initialize the global state of the Confidential Wrapped Token Program.",
+        "",
+        "# Notes",
+        "",
+        "Only the upgrade authority of the program may call this instruction, and only once."
+      ];
+      accounts: [
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Confidential Wrapped Token Program."];
+        },
+        {
+          name: "programData";
+          isMut: false;
+          isSigner: false;
+          docs: ["The program data of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "upgradeAuthority";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "The upgrade authority of the Confidential Wrapped Token Program."
+          ];
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          docs: ["The fee and rent payer."];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The System Program."];
+        }
+      ];
+      args: [
+        {
+          name: "admin";
+          type: "publicKey";
+        },
+        {
+          name: "guardian";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "setPaused";
+      docs: [
+        "Pause or unpause wrapping and unwrapping, for the given wrapper or, if none is given, for every wrapper.",
+        "",
+        "# Notes",
+        "",
+        "The admin and the guardian of the program may pause and unpause every wrapper as well as any single wrapper,",
+        "while the admin of a wrapper may only pause and unpause its own wrapper, unless it was paused by the admin",
+        "or the guardian of the program."
+      ];
+      accounts: [
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "The state of the Confidential Wrapped Token to pause or unpause, every wrapper if none is given."
+          ];
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "The admin or guardian of the program, or the admin of the wrapper."
+          ];
+        }
+      ];
+      args: [
+        {
+          name: "paused";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "transferAdmin";
+      docs: [
+        "Propose a new admin of the program, which only becomes the admin once it calls [`accept_admin`].",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the program may call this instruction, proposing another admin replaces the pending one."
+      ];
+      accounts: [
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the program."];
+        }
+      ];
+      args: [
+        {
+          name: "newAdmin";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "acceptAdmin";
+      docs: [
+        "Accept the admin role of the program proposed through [`transfer_admin`].",
+        "",
+        "# Notes",
+        "",
+        "Only the pending admin of the program may call this instruction."
+      ];
+      accounts: [
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "pendingAdmin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The pending admin of the program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setGuardian";
+      docs: [
+        "Replace the guardian of the program.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the program may call this instruction."
+      ];
+      accounts: [
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the program."];
+        }
+      ];
+      args: [
+        {
+          name: "guardian";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "setDefaultPolicy";
+      docs: [
+        "Update the policy applied to new wrappers, which does not affect existing wrappers.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the program may call this instruction."
+      ];
+      accounts: [
+        {
+          name: "globalConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the program."];
+        }
+      ];
+      args: [
+        {
+          name: "defaultWrapperAdmin";
+          type: {
+            option: "publicKey";
+          };
+        },
+        {
+          name: "defaultPaused";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "setFees";
+      docs: [
+        "Set the fees, in basis points, deducted from the SPL Tokens being wrapped and unwrapped.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction, and neither fee may exceed",
+        "[`MAX_FEE_BPS`]. As the payer of [`initialize`] never becomes the admin of the wrapper, whoever initialized",
+        "it cannot set its fees."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the wrapper or of the program."];
+        }
+      ];
+      args: [
+        {
+          name: "wrapFeeBps";
+          type: "u16";
+        },
+        {
+          name: "unwrapFeeBps";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "setLimits";
+      docs: [
+        "Set the maximum supply of the Confidential Wrapped Token Mint and the limits of the amount wrapped",
+        "in a single instruction, where `None` means unlimited.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction. Lowering the maximum supply",
+        "below the current supply only prevents further wrapping, unwrapping is unaffected."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the wrapper or of the program."];
+        }
+      ];
+      args: [
+        {
+          name: "maxSupply";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "maxWrapPerTx";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "minWrapAmount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setUnwrapLimit";
+      docs: [
+        "Set the maximum amount which may be unwrapped within any window of the given number of slots,",
+        "where `None` means unlimited.",
+        "",
+        "# Notes",
+        "",
+        "The admin of the wrapper or of the program may set any unwrap limit, while the guardian of the program",
+        "may only tighten it, by lowering the limit or lengthening the window. The amount already unwrapped within",
+        "the current window still counts towards the new limit."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "The admin of the wrapper or of the program, or the guardian of the program."
+          ];
+        }
+      ];
+      args: [
+        {
+          name: "unwrapLimit";
+          type: {
+            option: "u64";
+          };
+        },
+        {
+          name: "unwrapWindowSlots";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "withdrawFees";
+      docs: [
+        "Withdraw the given amount of collected fees from the fee vault into the given token account.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction.",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+          docs: ["The token account receiving the fees."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the wrapper or of the program."];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Program which owns the SPL Token Mint."];
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setTreasury";
+      docs: [
+        "Set the token account receiving the surplus of the vault swept through [`sweep_surplus`].",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: ["The admin of the wrapper or of the program."];
+        }
+      ];
+      args: [
+        {
+          name: "treasury";
+          type: {
+            option: "publicKey";
+          };
+        }
+      ];
+    },
+    {
+      name: "sweepSurplus";
+      docs: [
+        "Sweep the SPL Tokens held by the vault in excess of the supply of the Confidential Wrapped Token Mint,",
+        "such as tokens transferred directly into the vault, into the treasury of the wrapper.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless, as the surplus may only be sent to the treasury set through [`set_treasury`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "tokenVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault holding the wrapped SPL Tokens."];
+        },
+        {
+          name: "treasury";
+          isMut: true;
+          isSigner: false;
+          docs: ["The treasury of the wrapper, receiving the surplus."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Program which owns the SPL Token Mint."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeWrapper";
+      docs: [
+        "Close a wrapper whose Confidential Wrapped Token is no longer in circulation, refunding the rent of its",
+        "vault, fee vault and state to the given destination.",
+        "",
+        "# Notes",
+        "",
+        "Only the mint authority of the SPL Token Mint or the admin of the program may call this instruction, once the",
+        "supply of the Confidential Wrapped Token Mint is zero and both the vault and the fee vault are empty. Any",
+        "transfer fee withheld in either vault must be harvested beforehand.",
+        "",
+        "The Confidential Wrapped Token Mint cannot be closed and is left disabled, its mint authority being revoked,",
+        "so the variant cannot be wrapped again. As closing is permanent, the admin of the wrapper alone may not",
+        "close it."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: true;
+          isSigner: false;
+          docs: ["The mint of the Confidential Wrapped Token."];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "tokenVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault holding the wrapped SPL Tokens."];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "admin";
+          isMut: false;
+          isSigner: true;
+          docs: [
+            "The mint authority of the SPL Token Mint or the admin of the program."
+          ];
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+          docs: ["The account receiving the rent of the closed accounts."];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Program which owns the SPL Token Mint."];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateAuthority";
+      docs: [
+        "Move a wrapper created under the authority formerly shared by every wrapper to its own wrapper authority.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless. The wrapped SPL Tokens are moved to the vault of the wrapper authority",
+        "and the vault of the shared authority is closed to the admin of the wrapper, as whoever calls it did not pay",
+        "for that vault, then the wrapper authority takes over the fee vault as well as the mint, freeze and",
+        "confidential transfer authorities held by the shared authority.",
+        "",
+        "If the SPL Token Mint charges a transfer fee, the vault must hold enough surplus to cover the fee charged",
+        "on the move, and any transfer fee withheld in the vault must be harvested beforehand.",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "The mint of the Confidential Wrapped Token, still minted by the shared authority."
+          ];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "legacyTokenVault";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "The vault of the shared authority, holding the wrapped SPL Tokens until the migration."
+          ];
+        },
+        {
+          name: "tokenVault";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "The vault of the wrapper authority, which anyone may have created beforehand as it is an Associated Token Account."
+          ];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
+        },
+        {
+          name: "programAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: ["The authority formerly shared by every wrapper."];
+        },
+        {
+          name: "wrapperAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults."
+          ];
+        },
+        {
+          name: "admin";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "The admin of the wrapper, receiving the rent of the vault of the shared authority."
+          ];
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          docs: [
+            "The rent payer of the vault of the wrapper authority, if it has yet to be created."
+          ];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Program which owns the SPL Token Mint."];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Associated Token Program."];
+        },
+        {
+          name: "tokenExtensionsProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Extensions Program."];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The System Program."];
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "adoptLegacyWrapper";
+      docs: [
+        "Create the missing Wrapper Config of a wrapper initialized before the Wrapper Config existed, reconstructing",
+        "it from the Confidential Wrapped Token Mint and the vault.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless. The Confidential Wrapped Token Mint must still be minted by the authority",
+        "formerly shared by every wrapper and have the confidential transfer extension, otherwise it fails with",
+        "[`CtwError::NotLegacyWrapper`], and the vault must hold at least its supply. Such wrappers are always",
+        "the first variant of their SPL Token Mint.",
+        "",
+        "The confidential transfer authority, whether new accounts are approved and the auditor are read from the",
+        "extension, and the supply is recorded as the total wrapped so far. As the payer may be anyone, the admin is",
+        "chosen as for [`initialize`] without an admin: the default wrapper admin of the program, else the mint",
+        "authority of the SPL Token Mint, else the admin of the program. Like new wrappers, the adopted wrapper",
+        "starts paused if the [`GlobalConfig`] says so.",
+        "",
+        "A fee vault owned by the shared authority is created alongside, so that the wrapper can then be moved to its",
+        "own wrapper authority through [`migrate_authority`], without which it cannot be used."
+      ];
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+          docs: ["The mint of the token being wrapped."];
+        },
+        {
+          name: "confidentialMint";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The mint of the Confidential Wrapped Token, minted by the shared authority."
+          ];
+        },
+        {
+          name: "wrapperConfig";
+          isMut: true;
+          isSigner: false;
+          docs: ["The state of the Confidential Wrapped Token."];
+        },
+        {
+          name: "globalConfig";
+          isMut: false;
+          isSigner: false;
+          docs: ["The global state of the Confidential Wrapped Token Program."];
+        },
+        {
+          name: "tokenVault";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "The vault of the shared authority, holding the wrapped SPL Tokens."
+          ];
+        },
+        {
+          name: "feeVault";
+          isMut: true;
+          isSigner: false;
+          docs: ["The vault collecting the wrap and unwrap fees."];
+        },
+        {
+          name: "programAuthority";
+          isMut: false;
+          isSigner: false;
+          docs: ["The authority formerly shared by every wrapper."];
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+          docs: ["The rent payer."];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The Token Program which owns the SPL Token Mint."];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["The System Program."];
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "wrapperConfig";
+      docs: [
+        "The per-mint state of a Confidential Wrapped Token.",
+        "",
+        "This account is created alongside the Confidential Wrapped Token Mint and the vault, and",
+        "records which SPL Token Mint is being wrapped by which Confidential Wrapped Token Mint so that",
+        "integrators only need to fetch a single account instead of re-deriving every PDA."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "tokenMint";
+            docs: ["The SPL Token Mint being wrapped."];
+            type: "publicKey";
+          },
+          {
+            name: "tokenProgram";
+            docs: ["The Token Program which owns the SPL Token Mint."];
+            type: "publicKey";
+          },
+          {
+            name: "confidentialMint";
+            docs: ["The Confidential Wrapped Token Mint."];
+            type: "publicKey";
+          },
+          {
+            name: "tokenVault";
+            docs: ["The vault holding the wrapped SPL Tokens."];
+            type: "publicKey";
+          },
+          {
+            name: "feeVault";
+            docs: ["The vault collecting the wrap and unwrap fees."];
+            type: "publicKey";
+          },
+          {
+            name: "admin";
+            docs: [
+              "The admin of the wrapper, allowed to update the confidential transfer extension."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "confidentialTransferAuthority";
+            docs: [
+              "The confidential transfer authority of the Confidential Wrapped Token Mint."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "autoApproveNewAccounts";
+            docs: [
+              "Whether new CT-enabled Token Accounts are approved without the confidential transfer authority."
+            ];
+            type: "bool";
+          },
+          {
+            name: "auditorElgamalPubkey";
+            docs: [
+              "The auditor ElGamal public key of the Confidential Wrapped Token Mint, all zeroes if there is none."
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "auditorRotations";
+            docs: ["The number of times the auditor has been replaced."];
+            type: "u64";
+          },
+          {
+            name: "auditorHistory";
+            docs: [
+              "The previous auditors, as a ring buffer indexed by the number of rotations."
+            ];
+            type: {
+              array: [
+                {
+                  defined: "AuditorRecord";
+                },
+                4
+              ];
+            };
+          },
+          {
+            name: "bump";
+            docs: ["The bump of this account."];
+            type: "u8";
+          },
+          {
+            name: "confidentialMintBump";
+            docs: ["The bump of the Confidential Wrapped Token Mint."];
+            type: "u8";
+          },
+          {
+            name: "wrapperAuthorityBump";
+            docs: ["The bump of the wrapper authority."];
+            type: "u8";
+          },
+          {
+            name: "createdSlot";
+            docs: ["The slot at which the wrapper was initialized."];
+            type: "u64";
+          },
+          {
+            name: "totalWrapped";
+            docs: [
+              "The total amount of SPL Tokens ever wrapped, excluding fees."
+            ];
+            type: "u64";
+          },
+          {
+            name: "totalUnwrapped";
+            docs: [
+              "The total amount of SPL Tokens ever unwrapped, including fees."
+            ];
+            type: "u64";
+          },
+          {
+            name: "paused";
+            docs: [
+              "Whether wrapping and unwrapping are paused for this wrapper."
+            ];
+            type: "bool";
+          },
+          {
+            name: "wrapFeeBps";
+            docs: [
+              "The fee, in basis points, deducted from the SPL Tokens being wrapped."
+            ];
+            type: "u16";
+          },
+          {
+            name: "unwrapFeeBps";
+            docs: [
+              "The fee, in basis points, deducted from the SPL Tokens being unwrapped."
+            ];
+            type: "u16";
+          },
+          {
+            name: "totalFees";
+            docs: [
+              "The total amount of SPL Tokens ever received by the fee vault as fees, net of any transfer fee",
+              "charged on moving them into it."
+            ];
+            type: "u64";
+          },
+          {
+            name: "maxSupply";
+            docs: [
+              "The maximum supply of the Confidential Wrapped Token Mint, unlimited if there is none."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxWrapPerTx";
+            docs: [
+              "The maximum amount which may be wrapped in a single instruction, unlimited if there is none."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "minWrapAmount";
+            docs: [
+              "The minimum amount which may be wrapped in a single instruction."
+            ];
+            type: "u64";
+          },
+          {
+            name: "unwrapLimit";
+            docs: [
+              "The maximum amount which may be unwrapped within any window of `unwrap_window_slots` slots,",
+              "unlimited if there is none."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "unwrapWindowSlots";
+            docs: [
+              "The length, in slots, of the window over which the unwrap limit applies."
+            ];
+            type: "u64";
+          },
+          {
+            name: "unwrapOutflow";
+            docs: [
+              "The amount unwrapped within the window of `unwrap_window_slots` slots containing `unwrap_outflow_slot`."
+            ];
+            type: "u64";
+          },
+          {
+            name: "unwrapOutflowSlot";
+            docs: ["The slot at which the unwrap outflow was last updated."];
+            type: "u64";
+          },
+          {
+            name: "previousUnwrapOutflow";
+            docs: [
+              "The amount unwrapped within the window before the one of the unwrap outflow."
+            ];
+            type: "u64";
+          },
+          {
+            name: "previousUnwrapOutflowSlot";
+            docs: [
+              "The slot at which the previous unwrap outflow was last updated."
+            ];
+            type: "u64";
+          },
+          {
+            name: "treasury";
+            docs: [
+              "The token account receiving the surplus of the vault, none if it has not been set."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "auditorPolicy";
+            docs: [
+              "Who authorized the current auditor and confidential transfer authority."
+            ];
+            type: {
+              defined: "AuditorPolicy";
+            };
+          },
+          {
+            name: "variant";
+            docs: [
+              "The variant distinguishing this wrapper from the other wrappers of the same SPL Token Mint."
+            ];
+            type: "u16";
+          },
+          {
+            name: "pausedByGlobalAuthority";
+            docs: [
+              "Whether the wrapper was paused by the admin or guardian of the program, who alone may then unpause it."
+            ];
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "globalConfig";
+      docs: [
+        "The global state of the Confidential Wrapped Token Program.",
+        "",
+        "This account is created once by the upgrade authority of the program and holds the roles",
+        "which may act on every wrapper, such as pausing all of them at once, as well as the policy",
+        "applied to new wrappers."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "admin";
+            docs: [
+              "The admin of the program, allowed to act as the admin of any wrapper and to update this account."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "pendingAdmin";
+            docs: [
+              "The admin proposed through `transfer_admin`, which becomes the admin once it accepts."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "guardian";
+            docs: [
+              "The guardian of the program, allowed to pause and unpause any wrapper."
+            ];
+            type: "publicKey";
+          },
+          {
+            name: "paused";
+            docs: [
+              "Whether wrapping and unwrapping are paused for every wrapper."
+            ];
+            type: "bool";
+          },
+          {
+            name: "defaultWrapperAdmin";
+            docs: [
+              "The admin of new wrappers, the mint authority of the SPL Token Mint or else the admin of the program",
+              "if there is none."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "defaultPaused";
+            docs: [
+              "Whether new wrappers are paused until unpaused through `set_paused`."
+            ];
+            type: "bool";
+          },
+          {
+            name: "bump";
+            docs: ["The bump of this account."];
+            type: "u8";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "InitializeOptions";
+      docs: [
+        "The options of the confidential transfer extension of a new Confidential Wrapped Token Mint."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "autoApproveNewAccounts";
+            docs: [
+              "Whether new CT-enabled Token Accounts are approved without the confidential transfer authority."
+            ];
+            type: "bool";
+          },
+          {
+            name: "confidentialTransferAuthority";
+            docs: [
+              "The confidential transfer authority, defaults to the wrapper authority.",
+              "Any other authority must be co-signed by the mint authority of the SPL Token Mint or the admin of the program."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "auditorElgamalPubkey";
+            docs: [
+              "The auditor ElGamal public key, which must be co-signed by the mint authority of the SPL Token Mint",
+              "or the admin of the program.",
+              "`solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey` length is 32 but it doesn't impl Borsh."
+            ];
+            type: {
+              option: {
+                array: ["u8", 32];
+              };
+            };
+          },
+          {
+            name: "variant";
+            docs: [
+              "The variant of the wrapper, so that an SPL Token Mint may have several wrappers with their own auditor",
+              "and approval policy, each with its own vaults. The first variant is `0`."
+            ];
+            type: "u16";
+          },
+          {
+            name: "admin";
+            docs: [
+              "The admin of the wrapper, which must be co-signed by the mint authority of the SPL Token Mint",
+              "or the admin of the program. Defaults to the default wrapper admin of the global config,",
+              "or else the mint authority of the SPL Token Mint, or else the admin of the program."
+            ];
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "AuditorRecord";
+      docs: ["A previous auditor of a Confidential Wrapped Token Mint."];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "elgamalPubkey";
+            docs: [
+              "The auditor ElGamal public key, all zeroes if there was none."
+            ];
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "replacedSlot";
+            docs: ["The slot at which the auditor was replaced."];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AuditorPolicy";
+      docs: [
+        "Who authorized the auditor of a Confidential Wrapped Token Mint.",
+        "",
+        "As [`initialize`](crate::ctw_token::initialize) is permissionless, only the mint authority of the SPL Token Mint",
+        "or the admin of the program may choose who can decrypt the confidential transfers of a new wrapper, which the",
+        "admin of the wrapper, always chosen by either of them, may then replace."
+      ];
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "None";
+          },
+          {
+            name: "MintAuthority";
+          },
+          {
+            name: "GlobalAdmin";
+          },
+          {
+            name: "WrapperAdmin";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "WrapperInitialized";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenProgram";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenVault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "variant";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "payer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "Wrapped";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "fee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "vaultBalance";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "Unwrapped";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "owner";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "fee";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "vaultBalance";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AuditorRotated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "previousAuditorElgamalPubkey";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        },
+        {
+          name: "auditorElgamalPubkey";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "PauseUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "paused";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AdminTransferred";
+      fields: [
+        {
+          name: "previousAdmin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FeesWithdrawn";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "destination";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "SurplusSwept";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "treasury";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WrapperClosed";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AuthorityMigrated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "wrapperAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenVault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LegacyWrapperAdopted";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenVault";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "supply";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "auditorElgamalPubkey";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        },
+        {
+          name: "freezeAuthority";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FeesUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "wrapFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "unwrapFeeBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LimitsUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "maxSupply";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "maxWrapPerTx";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "minWrapAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UnwrapLimitUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "unwrapLimit";
+          type: {
+            option: "u64";
+          };
+          index: false;
+        },
+        {
+          name: "unwrapWindowSlots";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TreasuryUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "treasury";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfidentialMintUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "autoApproveNewAccounts";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "auditorElgamalPubkey";
+          type: {
+            array: ["u8", 32];
+          };
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AccountApproved";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AccountFreezeUpdated";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialTokenAccount";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "frozen";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "freezeAuthority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FreezeAuthoritySynced";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "confidentialMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "freezeAuthority";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GlobalInitialized";
+      fields: [
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "guardian";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AdminProposed";
+      fields: [
+        {
+          name: "admin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "pendingAdmin";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "GuardianUpdated";
+      fields: [
+        {
+          name: "previousGuardian";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "guardian";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "DefaultPolicyUpdated";
+      fields: [
+        {
+          name: "defaultWrapperAdmin";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "defaultPaused";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "slot";
+          type: "u64";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "PermanentDelegateNotSupported";
+      msg: "The SPL Token Mint has a permanent delegate which could transfer or burn the vault's tokens";
+    },
+    {
+      code: 6001;
+      name: "TransferHookNotSupported";
+      msg: "The SPL Token Mint has a transfer hook which could block transfers into or out of the vault";
+    },
+    {
+      code: 6002;
+      name: "NonTransferableNotSupported";
+      msg: "The SPL Token Mint is non-transferable and cannot be deposited into the vault";
+    },
+    {
+      code: 6003;
+      name: "DefaultAccountStateFrozenNotSupported";
+      msg: "The SPL Token Mint initializes new token accounts as frozen";
+    },
+    {
+      code: 6004;
+      name: "InterestBearingNotSupported";
+      msg: "The SPL Token Mint accrues interest which breaks the 1:1 equivalence with the wrapped token";
+    },
+    {
+      code: 6005;
+      name: "MintCloseAuthorityNotSupported";
+      msg: "The SPL Token Mint has a close authority and could be closed and re-created";
+    },
+    {
+      code: 6006;
+      name: "UnsupportedMintExtension";
+      msg: "The SPL Token Mint has an extension which is not supported";
+    },
+    {
+      code: 6007;
+      name: "MintAlreadyWrapped";
+      msg: "The SPL Token Mint already has a Confidential Wrapped Token Mint";
+    },
+    {
+      code: 6008;
+      name: "ZeroAmount";
+      msg: "The amount must be greater than zero";
+    },
+    {
+      code: 6009;
+      name: "InsufficientPublicBalance";
+      msg: "The confidential token account does not have enough public balance";
+    },
+    {
+      code: 6010;
+      name: "VaultInvariantViolated";
+      msg: "The vault does not hold enough tokens to back the Confidential Wrapped Token supply";
+    },
+    {
+      code: 6011;
+      name: "MathOverflow";
+      msg: "Math overflow";
+    },
+    {
+      code: 6012;
+      name: "ConfidentialTransferAuthorityNotProgram";
+      msg: "The wrapper authority is not the confidential transfer authority of the Confidential Wrapped Token Mint";
+    },
+    {
+      code: 6013;
+      name: "TokenAccountFrozen";
+      msg: "The SPL Token Account is frozen";
+    },
+    {
+      code: 6014;
+      name: "FreezeAuthorityMismatch";
+      msg: "The signer is not the freeze authority of the SPL Token Mint";
+    },
+    {
+      code: 6015;
+      name: "FreezeAuthorityNotProgram";
+      msg: "The wrapper authority is not the freeze authority of the Confidential Wrapped Token Mint";
+    },
+    {
+      code: 6016;
+      name: "Paused";
+      msg: "Wrapping and unwrapping are paused";
+    },
+    {
+      code: 6017;
+      name: "Unauthorized";
+      msg: "The signer is not allowed to perform this action";
+    },
+    {
+      code: 6018;
+      name: "NotUpgradeAuthority";
+      msg: "The signer is not the upgrade authority of the program";
+    },
+    {
+      code: 6019;
+      name: "FeeTooHigh";
+      msg: "The fee exceeds the maximum fee";
+    },
+    {
+      code: 6020;
+      name: "WrapAmountTooSmall";
+      msg: "The amount is below the minimum wrap amount";
+    },
+    {
+      code: 6021;
+      name: "WrapAmountTooLarge";
+      msg: "The amount is above the maximum wrap amount per transaction";
+    },
+    {
+      code: 6022;
+      name: "MaxSupplyExceeded";
+      msg: "Wrapping would exceed the maximum supply of the Confidential Wrapped Token";
+    },
+    {
+      code: 6023;
+      name: "InvalidLimits";
+      msg: "The minimum wrap amount exceeds the maximum wrap amount per transaction";
+    },
+    {
+      code: 6024;
+      name: "UnwrapLimitExceeded";
+      msg: "The amount exceeds the remaining unwrap capacity of the current window";
+    },
+    {
+      code: 6025;
+      name: "InvalidUnwrapWindow";
+      msg: "The unwrap window must be at least one slot";
+    },
+    {
+      code: 6026;
+      name: "SupplyNotZero";
+      msg: "The supply of the Confidential Wrapped Token is not zero";
+    },
+    {
+      code: 6027;
+      name: "VaultNotEmpty";
+      msg: "The vault or the fee vault still holds SPL Tokens";
+    },
+    {
+      code: 6028;
+      name: "TreasuryNotSet";
+      msg: "No treasury is set for the wrapper";
+    },
+    {
+      code: 6029;
+      name: "InvalidTreasury";
+      msg: "The token account is not the treasury of the wrapper";
+    },
+    {
+      code: 6030;
+      name: "NoSurplus";
+      msg: "The vault holds no surplus";
+    },
+    {
+      code: 6031;
+      name: "AuditorNotAuthorized";
+      msg: "Only the mint authority or an admin may choose the auditor";
+    },
+    {
+      code: 6032;
+      name: "AuthorityAlreadyMigrated";
+      msg: "The wrapper already has its own authority";
+    },
+    {
+      code: 6033;
+      name: "NotLegacyWrapper";
+      msg: "The Confidential Wrapped Token Mint was not created by a legacy wrapper";
+    },
+    {
+      code: 6034;
+      name: "AdminNotAuthorized";
+      msg: "Only the mint authority or the admin of the program may choose the wrapper admin";
+    },
+    {
+      code: 6035;
+      name: "ApprovalNotAuthorized";
+      msg: "Only the mint authority or the admin of the program may require approval";
+    },
+    {
+      code: 6036;
+      name: "FreezeAuthorityRevoked";
+      msg: "The freeze authority of the Confidential Wrapped Token Mint was revoked and cannot be restored";
+    },
+    {
+      code: 6037;
+      name: "AccountNotApproved";
+      msg: "The Confidential Token Account has not been approved yet";
+    }
+  ];
+};
+
+export const IDL: CtwToken = {
+  version: "0.1.0",
+  name: "ctw_token",
+  instructions: [
+    {
+      name: "initialize",
+      docs: [
+        "Initialize a Confidential Transfer enabled Token Extensions Mint for an existing SPL Token Mint.",
+        "This Confidential Transfer enabled Token Extensions Mint, or Confidential Wrapped Token Mint,",
+        "effectively represents the same underlying SPL Token but with the ability to use Token Extensions'",
+        "zk-powered confidential transfers which mask the amount being transferred.",
+        "",
+        "# Notes",
+        "",
+        "The approval policy, the confidential transfer authority, the auditor and the admin are configured through",
+        "the given [`InitializeOptions`], and may only differ from the defaults with the co-signature of the mint",
+        "authority of the SPL Token Mint or the admin of the program. Confidential Wrapped Tokens are 1:1 equivalents",
+        "of the SPL Token.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The SPL Token Mint for which we want to create a Confidential Transfers Mint Wrapper.",
+          ],
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The SPL Token Extensions Mint."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "The token vault, which anyone may have created beforehand as it is an Associated Token Account.",
+          ],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: ["The fee and rent payer."],
+        },
+        {
+          name: "auditorAuthority",
+          isMut: false,
+          isSigner: true,
+          isOptional: true,
+          docs: [
+            "The mint authority of the SPL Token Mint or the admin of the program, required to choose an auditor,",
+            "a confidential transfer authority other than the wrapper authority or the admin, or to require approval.",
+          ],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The Token Program which owns the SPL Token Mint, either the SPL Token Program or the Token Extensions Program.",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Associated Token Program."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The System Program."],
+        },
+      ],
+      args: [
+        {
+          name: "options",
+          type: {
+            defined: "InitializeOptions",
+          },
+        },
+      ],
+    },
+    {
+      name: "wrap",
+      docs: [
+        "Wrap the given token amount of an SPL Token into an equivalent amount of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "The integrator is responsible for passing in a TokenAccount for the `confidential_token_account` param",
+        "that has already been initialized and for which the [`ConfigureAccount`] as well as, if necessary,",
+        "the [`ApproveAccount`] instructions have been executed. If the wrapper requires approval of new accounts,",
+        "the admin approves them through [`approve_account`].",
+        "",
+        "After this instruction is called, the integrator is then free to call [`ApplyPendingBalance`]",
+        "in order to roll the token amount into the available balance of the Confidential Token Account.",
+        "",
+        "If the SPL Token Mint charges a transfer fee, only the amount actually received by the vault is minted.",
+        "",
+        "The wrap fee of the wrapper is deducted from the amount received by the vault and moved into the fee vault,",
+        "only the remainder is minted. If the SPL Token Mint charges a transfer fee, moving the fee is charged one too,",
+        "and only what the fee vault received counts towards the total fees of the wrapper.",
+        "",
+        "Frozen SPL Token Accounts cannot be wrapped from.",
+        "",
+        "The amount must be within the per-transaction limits of the wrapper, and the supply of the",
+        "Confidential Wrapped Token Mint may not exceed its maximum supply once minted.",
+        "",
+        "Fails with [`CtwError::Paused`] while either the wrapper or every wrapper is paused through [`set_paused`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer into the vault",
+        "and for the transfer of the fee into the fee vault must be passed in as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "confidentialTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+          docs: ["The authority of the source token account."],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: ["The fee and rent payer."],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Interface."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Interface."],
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "wrapTo",
+      docs: [
+        "Wrap the given token amount of an SPL Token into the Confidential Token Account of another owner, such as",
+        "a company paying out its employees.",
+        "",
+        "# Notes",
+        "",
+        "Unlike [`wrap`], the minted tokens are left in the public balance of the recipient's account, as depositing",
+        "them into its confidential balance requires the signature of its owner, who may [`Deposit`] them later on.",
+        "The recipient's account therefore only needs to exist, not to be configured for confidential transfers,",
+        "unless the wrapper requires new accounts to be approved, in which case it fails with",
+        "[`CtwError::AccountNotApproved`] until the account is configured and approved.",
+        "",
+        "The fees, limits and pause of the wrapper apply as they do to [`wrap`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer into the vault",
+        "and for the transfer of the fee into the fee vault must be passed in as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "confidentialTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+          docs: ["The authority of the source token account."],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: ["The fee and rent payer."],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Interface."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Interface."],
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "unwrap",
+      docs: [
+        "Unwrap the given token amount of a Confidential Wrapped Token back into it's corresponding",
+        "SPL Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "The integrator is responsible for assuring that the user has enough non-confidential",
+        "balance in order to unwrap and redeem for the underlying token.",
+        "This can be achieved by having the [`Withdraw`] instruction being successfully executed beforehand.",
+        "",
+        "If the SPL Token Mint charges a transfer fee, it is withheld from the amount received by the user.",
+        "",
+        "The unwrap fee of the wrapper is deducted from the amount sent to the user and moved into the fee vault.",
+        "If the SPL Token Mint charges a transfer fee, moving the fee is charged one too, and only what the fee vault",
+        "received counts towards the total fees of the wrapper.",
+        "",
+        "Frozen SPL Token Accounts cannot be unwrapped into.",
+        "",
+        "The amount, including the fee, counts towards the unwrap limit of the wrapper set through [`set_unwrap_limit`].",
+        "",
+        "Fails with [`CtwError::Paused`] while either the wrapper or every wrapper is paused through [`set_paused`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it for the transfer out of the vault",
+        "and for the transfer of the fee into the fee vault must be passed in as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "confidentialTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+          docs: ["The authority of the source token account."],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: ["The fee and rent payer."],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Interface."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Interface."],
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "verifyBacking",
+      docs: [
+        "Verify that the supply of a Confidential Wrapped Token is fully backed by the SPL Tokens held in the vault.",
+        "",
+        "# Notes",
+        "",
+        "This permissionless instruction does not modify any account, it logs the supply and the vault balance",
+        "and fails if the vault holds less than the supply, so it can be called by anyone, such as a keeper.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "tokenVault",
+          isMut: false,
+          isSigner: false,
+          docs: ["The token vault."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updateConfidentialMint",
+      docs: [
+        "Update whether new CT-enabled Token Accounts must be approved and the auditor of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program, or the mint authority of the SPL Token Mint, may call this",
+        "instruction, and only if the wrapper authority is the confidential transfer authority of the Confidential",
+        "Wrapped Token Mint.",
+        "",
+        "A new auditor is recorded in the [`AuditorPolicy`] of the wrapper along with who chose it, the admin of the",
+        "wrapper having itself been chosen by the mint authority of the SPL Token Mint or the admin of the program.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "The admin of the wrapper or of the program, or the mint authority of the SPL Token Mint.",
+          ],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [
+        {
+          name: "autoApproveNewAccounts",
+          type: "bool",
+        },
+        {
+          name: "auditorElgamalPubkey",
+          type: {
+            option: {
+              array: ["u8", 32],
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "rotateAuditor",
+      docs: [
+        "Rotate the auditor of a Confidential Wrapped Token Mint, keeping whether new CT-enabled Token Accounts",
+        "must be approved as is.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program, or the mint authority of the SPL Token Mint, may call this",
+        "instruction, and only if the wrapper authority is the confidential transfer authority of the Confidential",
+        "Wrapped Token Mint.",
+        "",
+        "A new auditor is recorded in the [`AuditorPolicy`] of the wrapper along with who chose it, the admin of the",
+        "wrapper having itself been chosen by the mint authority of the SPL Token Mint or the admin of the program.",
+        "",
+        "The previous auditor is recorded in the history of the wrapper.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "The admin of the wrapper or of the program, or the mint authority of the SPL Token Mint.",
+          ],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [
+        {
+          name: "auditorElgamalPubkey",
+          type: {
+            array: ["u8", 32],
+          },
+        },
+      ],
+    },
+    {
+      name: "approveAccount",
+      docs: [
+        "Approve a CT-enabled Token Account of a Confidential Wrapped Token Mint which requires approval of new accounts.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction, and only if the wrapper authority",
+        "is the confidential transfer authority of the Confidential Wrapped Token Mint.",
+        "",
+        "The Confidential Token Account must have been configured with [`ConfigureAccount`] beforehand.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "confidentialTokenAccount",
+          isMut: true,
+          isSigner: false,
+          docs: ["The Confidential Token Account to approve."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the wrapper or of the program."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "syncFreezeAuthority",
+      docs: [
+        "Mirror the current freeze authority of the SPL Token Mint onto the Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless. While the SPL Token Mint has a freeze authority, the wrapper authority",
+        "holds the freeze authority of the Confidential Wrapped Token Mint on its behalf, and once the SPL Token Mint's",
+        "freeze authority is revoked, the wrapper authority revokes its own through `SetAuthority`.",
+        "",
+        "Wrappers whose Confidential Wrapped Token Mint has a freeze authority other than the wrapper authority",
+        "can only be synced while it matches the SPL Token Mint's freeze authority. Those whose Confidential Wrapped",
+        "Token Mint's freeze authority was revoked while the SPL Token Mint still has one fail with",
+        "[`CtwError::FreezeAuthorityRevoked`], as `SetAuthority` cannot restore a revoked authority.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "freezeAccount",
+      docs: [
+        "Freeze a Confidential Token Account of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "Only the current freeze authority of the SPL Token Mint may call this instruction.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "confidentialTokenAccount",
+          isMut: true,
+          isSigner: false,
+          docs: ["The Confidential Token Account to freeze or thaw."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "freezeAuthority",
+          isMut: false,
+          isSigner: true,
+          docs: ["The freeze authority of the SPL Token Mint."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "thawAccount",
+      docs: [
+        "Thaw a frozen Confidential Token Account of a Confidential Wrapped Token Mint.",
+        "",
+        "# Notes",
+        "",
+        "Only the current freeze authority of the SPL Token Mint may call this instruction.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "confidentialTokenAccount",
+          isMut: true,
+          isSigner: false,
+          docs: ["The Confidential Token Account to freeze or thaw."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "freezeAuthority",
+          isMut: false,
+          isSigner: true,
+          docs: ["The freeze authority of the SPL Token Mint."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeGlobal",
+      docs: [
+        "Initialize the global state of the Confidential Wrapped Token Program.",
+        "",
+        "# Notes",
+        "",
+        "Only the upgrade authority of the program may call this instruction, and only once.",
+      ],
+      accounts: [
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Confidential Wrapped Token Program."],
+        },
+        {
+          name: "programData",
+          isMut: false,
+          isSigner: false,
+          docs: ["The program data of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "upgradeAuthority",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "The upgrade authority of the Confidential Wrapped Token Program.",
+          ],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: ["The fee and rent payer."],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The System Program."],
+        },
+      ],
+      args: [
+        {
+          name: "admin",
+          type: "publicKey",
+        },
+        {
+          name: "guardian",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setPaused",
+      docs: [
+        "Pause or unpause wrapping and unwrapping, for the given wrapper or, if none is given, for every wrapper.",
+        "",
+        "# Notes",
+        "",
+        "The admin and the guardian of the program may pause and unpause every wrapper as well as any single wrapper,",
+        "while the admin of a wrapper may only pause and unpause its own wrapper, unless it was paused by the admin",
+        "or the guardian of the program.",
+      ],
+      accounts: [
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "The state of the Confidential Wrapped Token to pause or unpause, every wrapper if none is given.",
+          ],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "The admin or guardian of the program, or the admin of the wrapper.",
+          ],
+        },
+      ],
+      args: [
+        {
+          name: "paused",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "transferAdmin",
+      docs: [
+        "Propose a new admin of the program, which only becomes the admin once it calls [`accept_admin`].",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the program may call this instruction, proposing another admin replaces the pending one.",
+      ],
+      accounts: [
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the program."],
+        },
+      ],
+      args: [
+        {
+          name: "newAdmin",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "acceptAdmin",
+      docs: [
+        "Accept the admin role of the program proposed through [`transfer_admin`].",
+        "",
+        "# Notes",
+        "",
+        "Only the pending admin of the program may call this instruction.",
+      ],
+      accounts: [
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "pendingAdmin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The pending admin of the program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setGuardian",
+      docs: [
+        "Replace the guardian of the program.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the program may call this instruction.",
+      ],
+      accounts: [
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the program."],
+        },
+      ],
+      args: [
+        {
+          name: "guardian",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setDefaultPolicy",
+      docs: [
+        "Update the policy applied to new wrappers, which does not affect existing wrappers.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the program may call this instruction.",
+      ],
+      accounts: [
+        {
+          name: "globalConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the program."],
+        },
+      ],
+      args: [
+        {
+          name: "defaultWrapperAdmin",
+          type: {
+            option: "publicKey",
+          },
+        },
+        {
+          name: "defaultPaused",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "setFees",
+      docs: [
+        "Set the fees, in basis points, deducted from the SPL Tokens being wrapped and unwrapped.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction, and neither fee may exceed",
+        "[`MAX_FEE_BPS`]. As the payer of [`initialize`] never becomes the admin of the wrapper, whoever initialized",
+        "it cannot set its fees.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the wrapper or of the program."],
+        },
+      ],
+      args: [
+        {
+          name: "wrapFeeBps",
+          type: "u16",
+        },
+        {
+          name: "unwrapFeeBps",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "setLimits",
+      docs: [
+        "Set the maximum supply of the Confidential Wrapped Token Mint and the limits of the amount wrapped",
+        "in a single instruction, where `None` means unlimited.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction. Lowering the maximum supply",
+        "below the current supply only prevents further wrapping, unwrapping is unaffected.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the wrapper or of the program."],
+        },
+      ],
+      args: [
+        {
+          name: "maxSupply",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "maxWrapPerTx",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "minWrapAmount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "setUnwrapLimit",
+      docs: [
+        "Set the maximum amount which may be unwrapped within any window of the given number of slots,",
+        "where `None` means unlimited.",
+        "",
+        "# Notes",
+        "",
+        "The admin of the wrapper or of the program may set any unwrap limit, while the guardian of the program",
+        "may only tighten it, by lowering the limit or lengthening the window. The amount already unwrapped within",
+        "the current window still counts towards the new limit.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "The admin of the wrapper or of the program, or the guardian of the program.",
+          ],
+        },
+      ],
+      args: [
+        {
+          name: "unwrapLimit",
+          type: {
+            option: "u64",
+          },
+        },
+        {
+          name: "unwrapWindowSlots",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "withdrawFees",
+      docs: [
+        "Withdraw the given amount of collected fees from the fee vault into the given token account.",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction.",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+          docs: ["The token account receiving the fees."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the wrapper or of the program."],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Program which owns the SPL Token Mint."],
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "setTreasury",
+      docs: [
+        "Set the token account receiving the surplus of the vault swept through [`sweep_surplus`].",
+        "",
+        "# Notes",
+        "",
+        "Only the admin of the wrapper or of the program may call this instruction.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: ["The admin of the wrapper or of the program."],
+        },
+      ],
+      args: [
+        {
+          name: "treasury",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "sweepSurplus",
+      docs: [
+        "Sweep the SPL Tokens held by the vault in excess of the supply of the Confidential Wrapped Token Mint,",
+        "such as tokens transferred directly into the vault, into the treasury of the wrapper.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless, as the surplus may only be sent to the treasury set through [`set_treasury`].",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault holding the wrapped SPL Tokens."],
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+          docs: ["The treasury of the wrapper, receiving the surplus."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Program which owns the SPL Token Mint."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeWrapper",
+      docs: [
+        "Close a wrapper whose Confidential Wrapped Token is no longer in circulation, refunding the rent of its",
+        "vault, fee vault and state to the given destination.",
+        "",
+        "# Notes",
+        "",
+        "Only the mint authority of the SPL Token Mint or the admin of the program may call this instruction, once the",
+        "supply of the Confidential Wrapped Token Mint is zero and both the vault and the fee vault are empty. Any",
+        "transfer fee withheld in either vault must be harvested beforehand.",
+        "",
+        "The Confidential Wrapped Token Mint cannot be closed and is left disabled, its mint authority being revoked,",
+        "so the variant cannot be wrapped again. As closing is permanent, the admin of the wrapper alone may not",
+        "close it.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: ["The mint of the Confidential Wrapped Token."],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault holding the wrapped SPL Tokens."],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "admin",
+          isMut: false,
+          isSigner: true,
+          docs: [
+            "The mint authority of the SPL Token Mint or the admin of the program.",
+          ],
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+          docs: ["The account receiving the rent of the closed accounts."],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Program which owns the SPL Token Mint."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "migrateAuthority",
+      docs: [
+        "Move a wrapper created under the authority formerly shared by every wrapper to its own wrapper authority.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless. The wrapped SPL Tokens are moved to the vault of the wrapper authority",
+        "and the vault of the shared authority is closed to the admin of the wrapper, as whoever calls it did not pay",
+        "for that vault, then the wrapper authority takes over the fee vault as well as the mint, freeze and",
+        "confidential transfer authorities held by the shared authority.",
+        "",
+        "If the SPL Token Mint charges a transfer fee, the vault must hold enough surplus to cover the fee charged",
+        "on the move, and any transfer fee withheld in the vault must be harvested beforehand.",
+        "",
+        "If the SPL Token Mint has a transfer hook, the extra accounts required by it must be passed in as remaining accounts.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "The mint of the Confidential Wrapped Token, still minted by the shared authority.",
+          ],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "legacyTokenVault",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "The vault of the shared authority, holding the wrapped SPL Tokens until the migration.",
+          ],
+        },
+        {
+          name: "tokenVault",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "The vault of the wrapper authority, which anyone may have created beforehand as it is an Associated Token Account.",
+          ],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "programAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: ["The authority formerly shared by every wrapper."],
+        },
+        {
+          name: "wrapperAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The authority of the wrapper, which mints the Confidential Wrapped Token and owns the vaults.",
+          ],
+        },
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "The admin of the wrapper, receiving the rent of the vault of the shared authority.",
+          ],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: [
+            "The rent payer of the vault of the wrapper authority, if it has yet to be created.",
+          ],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Program which owns the SPL Token Mint."],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Associated Token Program."],
+        },
+        {
+          name: "tokenExtensionsProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The Token Extensions Program."],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The System Program."],
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "adoptLegacyWrapper",
+      docs: [
+        "Create the missing Wrapper Config of a wrapper initialized before the Wrapper Config existed, reconstructing",
+        "it from the Confidential Wrapped Token Mint and the vault.",
+        "",
+        "# Notes",
+        "",
+        "This instruction is permissionless. The Confidential Wrapped Token Mint must still be minted by the authority",
+        "formerly shared by every wrapper and have the confidential transfer extension, otherwise it fails with",
+        "[`CtwError::NotLegacyWrapper`], and the vault must hold at least its supply. Such wrappers are always",
+        "the first variant of their SPL Token Mint.",
+        "",
+        "The confidential transfer authority, whether new accounts are approved and the auditor are read from the",
+        "extension, and the supply is recorded as the total wrapped so far. As the payer may be anyone, the admin is",
+        "chosen as for [`initialize`] without an admin: the default wrapper admin of the program, else the mint",
+        "authority of the SPL Token Mint, else the admin of the program. Like new wrappers, the adopted wrapper",
+        "starts paused if the [`GlobalConfig`] says so.",
+        "",
+        "A fee vault owned by the shared authority is created alongside, so that the wrapper can then be moved to its",
+        "own wrapper authority through [`migrate_authority`], without which it cannot be used.",
+      ],
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+          docs: ["The mint of the token being wrapped."],
+        },
+        {
+          name: "confidentialMint",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The mint of the Confidential Wrapped Token, minted by the shared authority.",
+          ],
+        },
+        {
+          name: "wrapperConfig",
+          isMut: true,
+          isSigner: false,
+          docs: ["The state of the Confidential Wrapped Token."],
+        },
+        {
+          name: "globalConfig",
+          isMut: false,
+          isSigner: false,
+          docs: ["The global state of the Confidential Wrapped Token Program."],
+        },
+        {
+          name: "tokenVault",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "The vault of the shared authority, holding the wrapped SPL Tokens.",
+          ],
+        },
+        {
+          name: "feeVault",
+          isMut: true,
+          isSigner: false,
+          docs: ["The vault collecting the wrap and unwrap fees."],
+        },
+        {
+          name: "programAuthority",
+          isMut: false,
+          isSigner: false,
+          docs: ["The authority formerly shared by every wrapper."],
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+          docs: ["The rent payer."],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
           isSigner: false,
-          docs: ["The authority of the Confidential Wrapper Token Program."],
+          docs: ["The Token Program which owns the SPL Token Mint."],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["The System Program."],
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "wrapperConfig",
+      docs: [
+        "The per-mint state of a Confidential Wrapped Token.",
+        "",
+        "This account is created alongside the Confidential Wrapped Token Mint and the vault, and",
+        "records which SPL Token Mint is being wrapped by which Confidential Wrapped Token Mint so that",
+        "integrators only need to fetch a single account instead of re-deriving every PDA.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "tokenMint",
+            docs: ["The SPL Token Mint being wrapped."],
+            type: "publicKey",
+          },
+          {
+            name: "tokenProgram",
+            docs: ["The Token Program which owns the SPL Token Mint."],
+            type: "publicKey",
+          },
+          {
+            name: "confidentialMint",
+            docs: ["The Confidential Wrapped Token Mint."],
+            type: "publicKey",
+          },
+          {
+            name: "tokenVault",
+            docs: ["The vault holding the wrapped SPL Tokens."],
+            type: "publicKey",
+          },
+          {
+            name: "feeVault",
+            docs: ["The vault collecting the wrap and unwrap fees."],
+            type: "publicKey",
+          },
+          {
+            name: "admin",
+            docs: [
+              "The admin of the wrapper, allowed to update the confidential transfer extension.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "confidentialTransferAuthority",
+            docs: [
+              "The confidential transfer authority of the Confidential Wrapped Token Mint.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "autoApproveNewAccounts",
+            docs: [
+              "Whether new CT-enabled Token Accounts are approved without the confidential transfer authority.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "auditorElgamalPubkey",
+            docs: [
+              "The auditor ElGamal public key of the Confidential Wrapped Token Mint, all zeroes if there is none.",
+            ],
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "auditorRotations",
+            docs: ["The number of times the auditor has been replaced."],
+            type: "u64",
+          },
+          {
+            name: "auditorHistory",
+            docs: [
+              "The previous auditors, as a ring buffer indexed by the number of rotations.",
+            ],
+            type: {
+              array: [
+                {
+                  defined: "AuditorRecord",
+                },
+                4,
+              ],
+            },
+          },
+          {
+            name: "bump",
+            docs: ["The bump of this account."],
+            type: "u8",
+          },
+          {
+            name: "confidentialMintBump",
+            docs: ["The bump of the Confidential Wrapped Token Mint."],
+            type: "u8",
+          },
+          {
+            name: "wrapperAuthorityBump",
+            docs: ["The bump of the wrapper authority."],
+            type: "u8",
+          },
+          {
+            name: "createdSlot",
+            docs: ["The slot at which the wrapper was initialized."],
+            type: "u64",
+          },
+          {
+            name: "totalWrapped",
+            docs: [
+              "The total amount of SPL Tokens ever wrapped, excluding fees.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "totalUnwrapped",
+            docs: [
+              "The total amount of SPL Tokens ever unwrapped, including fees.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "paused",
+            docs: [
+              "Whether wrapping and unwrapping are paused for this wrapper.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "wrapFeeBps",
+            docs: [
+              "The fee, in basis points, deducted from the SPL Tokens being wrapped.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "unwrapFeeBps",
+            docs: [
+              "The fee, in basis points, deducted from the SPL Tokens being unwrapped.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "totalFees",
+            docs: [
+              "The total amount of SPL Tokens ever received by the fee vault as fees, net of any transfer fee",
+              "charged on moving them into it.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "maxSupply",
+            docs: [
+              "The maximum supply of the Confidential Wrapped Token Mint, unlimited if there is none.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxWrapPerTx",
+            docs: [
+              "The maximum amount which may be wrapped in a single instruction, unlimited if there is none.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "minWrapAmount",
+            docs: [
+              "The minimum amount which may be wrapped in a single instruction.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "unwrapLimit",
+            docs: [
+              "The maximum amount which may be unwrapped within any window of `unwrap_window_slots` slots,",
+              "unlimited if there is none.",
+            ],
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "unwrapWindowSlots",
+            docs: [
+              "The length, in slots, of the window over which the unwrap limit applies.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "unwrapOutflow",
+            docs: [
+              "The amount unwrapped within the window of `unwrap_window_slots` slots containing `unwrap_outflow_slot`.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "unwrapOutflowSlot",
+            docs: ["The slot at which the unwrap outflow was last updated."],
+            type: "u64",
+          },
+          {
+            name: "previousUnwrapOutflow",
+            docs: [
+              "The amount unwrapped within the window before the one of the unwrap outflow.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "previousUnwrapOutflowSlot",
+            docs: [
+              "The slot at which the previous unwrap outflow was last updated.",
+            ],
+            type: "u64",
+          },
+          {
+            name: "treasury",
+            docs: [
+              "The token account receiving the surplus of the vault, none if it has not been set.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "auditorPolicy",
+            docs: [
+              "Who authorized the current auditor and confidential transfer authority.",
+            ],
+            type: {
+              defined: "AuditorPolicy",
+            },
+          },
+          {
+            name: "variant",
+            docs: [
+              "The variant distinguishing this wrapper from the other wrappers of the same SPL Token Mint.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "pausedByGlobalAuthority",
+            docs: [
+              "Whether the wrapper was paused by the admin or guardian of the program, who alone may then unpause it.",
+            ],
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "globalConfig",
+      docs: [
+        "The global state of the Confidential Wrapped Token Program.",
+        "",
+        "This account is created once by the upgrade authority of the program and holds the roles",
+        "which may act on every wrapper, such as pausing all of them at once, as well as the policy",
+        "applied to new wrappers.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "admin",
+            docs: [
+              "The admin of the program, allowed to act as the admin of any wrapper and to update this account.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "pendingAdmin",
+            docs: [
+              "The admin proposed through `transfer_admin`, which becomes the admin once it accepts.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "guardian",
+            docs: [
+              "The guardian of the program, allowed to pause and unpause any wrapper.",
+            ],
+            type: "publicKey",
+          },
+          {
+            name: "paused",
+            docs: [
+              "Whether wrapping and unwrapping are paused for every wrapper.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "defaultWrapperAdmin",
+            docs: [
+              "The admin of new wrappers, the mint authority of the SPL Token Mint or else the admin of the program",
+              "if there is none.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "defaultPaused",
+            docs: [
+              "Whether new wrappers are paused until unpaused through `set_paused`.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "bump",
+            docs: ["The bump of this account."],
+            type: "u8",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "InitializeOptions",
+      docs: [
+        "The options of the confidential transfer extension of a new Confidential Wrapped Token Mint.",
+      ],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "autoApproveNewAccounts",
+            docs: [
+              "Whether new CT-enabled Token Accounts are approved without the confidential transfer authority.",
+            ],
+            type: "bool",
+          },
+          {
+            name: "confidentialTransferAuthority",
+            docs: [
+              "The confidential transfer authority, defaults to the wrapper authority.",
+              "Any other authority must be co-signed by the mint authority of the SPL Token Mint or the admin of the program.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "auditorElgamalPubkey",
+            docs: [
+              "The auditor ElGamal public key, which must be co-signed by the mint authority of the SPL Token Mint",
+              "or the admin of the program.",
+              "`solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey` length is 32 but it doesn't impl Borsh.",
+            ],
+            type: {
+              option: {
+                array: ["u8", 32],
+              },
+            },
+          },
+          {
+            name: "variant",
+            docs: [
+              "The variant of the wrapper, so that an SPL Token Mint may have several wrappers with their own auditor",
+              "and approval policy, each with its own vaults. The first variant is `0`.",
+            ],
+            type: "u16",
+          },
+          {
+            name: "admin",
+            docs: [
+              "The admin of the wrapper, which must be co-signed by the mint authority of the SPL Token Mint",
+              "or the admin of the program. Defaults to the default wrapper admin of the global config,",
+              "or else the mint authority of the SPL Token Mint, or else the admin of the program.",
+            ],
+            type: {
+              option: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "AuditorRecord",
+      docs: ["A previous auditor of a Confidential Wrapped Token Mint."],
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "elgamalPubkey",
+            docs: [
+              "The auditor ElGamal public key, all zeroes if there was none.",
+            ],
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "replacedSlot",
+            docs: ["The slot at which the auditor was replaced."],
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "AuditorPolicy",
+      docs: [
+        "Who authorized the auditor of a Confidential Wrapped Token Mint.",
+        "",
+        "As [`initialize`](crate::ctw_token::initialize) is permissionless, only the mint authority of the SPL Token Mint",
+        "or the admin of the program may choose who can decrypt the confidential transfers of a new wrapper, which the",
+        "admin of the wrapper, always chosen by either of them, may then replace.",
+      ],
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "None",
+          },
+          {
+            name: "MintAuthority",
+          },
+          {
+            name: "GlobalAdmin",
+          },
+          {
+            name: "WrapperAdmin",
+          },
+        ],
+      },
+    },
+  ],
+  events: [
+    {
+      name: "WrapperInitialized",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tokenProgram",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tokenVault",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "variant",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "payer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "Wrapped",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "owner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "recipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "fee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "vaultBalance",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "Unwrapped",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "owner",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "fee",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "vaultBalance",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AuditorRotated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "previousAuditorElgamalPubkey",
+          type: {
+            array: ["u8", 32],
+          },
+          index: false,
+        },
+        {
+          name: "auditorElgamalPubkey",
+          type: {
+            array: ["u8", 32],
+          },
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "PauseUpdated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "paused",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AdminTransferred",
+      fields: [
+        {
+          name: "previousAdmin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "FeesWithdrawn",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "destination",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "SurplusSwept",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "treasury",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WrapperClosed",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AuthorityMigrated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "wrapperAuthority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tokenVault",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "LegacyWrapperAdopted",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tokenVault",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "supply",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "auditorElgamalPubkey",
+          type: {
+            array: ["u8", 32],
+          },
+          index: false,
+        },
+        {
+          name: "freezeAuthority",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "FeesUpdated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "wrapFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "unwrapFeeBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "LimitsUpdated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "maxSupply",
+          type: {
+            option: "u64",
+          },
+          index: false,
+        },
+        {
+          name: "maxWrapPerTx",
+          type: {
+            option: "u64",
+          },
+          index: false,
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-          docs: ["The authority of the source token account."],
+          name: "minWrapAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-          docs: ["The fee and rent payer."],
+          name: "admin",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Token Interface."],
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "UnwrapLimitUpdated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenExtensionsProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Token Interface."],
+          name: "unwrapLimit",
+          type: {
+            option: "u64",
+          },
+          index: false,
+        },
+        {
+          name: "unwrapWindowSlots",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "TreasuryUpdated",
+      fields: [
         {
-          name: "amount",
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "treasury",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
           type: "u64",
+          index: false,
         },
       ],
     },
     {
-      name: "unwrap",
-      docs: [
-        "Unwrap the given token amount of a Confidential Wrapped Token back into it's corresponding",
-        "SPL Token Mint.",
-        "",
-        "# Notes",
-        "",
-        "The integrator is responsible for assuring that the user has enough non-confidential",
-        "balance in order to unwrap and redeem for the underlying token.",
-        "This can be achieved by having the [`Withdraw`] instruction being successfully executed beforehand.",
+      name: "ConfidentialMintUpdated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "autoApproveNewAccounts",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "auditorElgamalPubkey",
+          type: {
+            array: ["u8", 32],
+          },
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
       ],
-      accounts: [
+    },
+    {
+      name: "AccountApproved",
+      fields: [
         {
           name: "tokenMint",
-          isMut: false,
-          isSigner: false,
-          docs: ["The mint of the token being wrapped."],
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenVault",
-          isMut: true,
-          isSigner: false,
+          name: "confidentialTokenAccount",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AccountFreezeUpdated",
+      fields: [
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
         },
         {
           name: "confidentialMint",
-          isMut: true,
-          isSigner: false,
-          docs: ["The mint of the token being wrapped."],
+          type: "publicKey",
+          index: false,
         },
         {
           name: "confidentialTokenAccount",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "programAuthority",
-          isMut: false,
-          isSigner: false,
-          docs: ["The authority of the Confidential Wrapper Token Program."],
+          name: "frozen",
+          type: "bool",
+          index: false,
         },
         {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-          docs: ["The authority of the source token account."],
+          name: "freezeAuthority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-          docs: ["The fee and rent payer."],
+          name: "slot",
+          type: "u64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "FreezeAuthoritySynced",
+      fields: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Token Interface."],
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenExtensionsProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["The Token Interface."],
+          name: "confidentialMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "freezeAuthority",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "GlobalInitialized",
+      fields: [
         {
-          name: "amount",
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "guardian",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "AdminProposed",
+      fields: [
+        {
+          name: "admin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "pendingAdmin",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "GuardianUpdated",
+      fields: [
+        {
+          name: "previousGuardian",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "guardian",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "slot",
+          type: "u64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "DefaultPolicyUpdated",
+      fields: [
+        {
+          name: "defaultWrapperAdmin",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "defaultPaused",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "slot",
           type: "u64",
+          index: false,
         },
       ],
     },
   ],
+  errors: [
+    {
+      code: 6000,
+      name: "PermanentDelegateNotSupported",
+      msg: "The SPL Token Mint has a permanent delegate which could transfer or burn the vault's tokens",
+    },
+    {
+      code: 6001,
+      name: "TransferHookNotSupported",
+      msg: "The SPL Token Mint has a transfer hook which could block transfers into or out of the vault",
+    },
+    {
+      code: 6002,
+      name: "NonTransferableNotSupported",
+      msg: "The SPL Token Mint is non-transferable and cannot be deposited into the vault",
+    },
+    {
+      code: 6003,
+      name: "DefaultAccountStateFrozenNotSupported",
+      msg: "The SPL Token Mint initializes new token accounts as frozen",
+    },
+    {
+      code: 6004,
+      name: "InterestBearingNotSupported",
+      msg: "The SPL Token Mint accrues interest which breaks the 1:1 equivalence with the wrapped token",
+    },
+    {
+      code: 6005,
+      name: "MintCloseAuthorityNotSupported",
+      msg: "The SPL Token Mint has a close authority and could be closed and re-created",
+    },
+    {
+      code: 6006,
+      name: "UnsupportedMintExtension",
+      msg: "The SPL Token Mint has an extension which is not supported",
+    },
+    {
+      code: 6007,
+      name: "MintAlreadyWrapped",
+      msg: "The SPL Token Mint already has a Confidential Wrapped Token Mint",
+    },
+    {
+      code: 6008,
+      name: "ZeroAmount",
+      msg: "The amount must be greater than zero",
+    },
+    {
+      code: 6009,
+      name: "InsufficientPublicBalance",
+      msg: "The confidential token account does not have enough public balance",
+    },
+    {
+      code: 6010,
+      name: "VaultInvariantViolated",
+      msg: "The vault does not hold enough tokens to back the Confidential Wrapped Token supply",
+    },
+    {
+      code: 6011,
+      name: "MathOverflow",
+      msg: "Math overflow",
+    },
+    {
+      code: 6012,
+      name: "ConfidentialTransferAuthorityNotProgram",
+      msg: "The wrapper authority is not the confidential transfer authority of the Confidential Wrapped Token Mint",
+    },
+    {
+      code: 6013,
+      name: "TokenAccountFrozen",
+      msg: "The SPL Token Account is frozen",
+    },
+    {
+      code: 6014,
+      name: "FreezeAuthorityMismatch",
+      msg: "The signer is not the freeze authority of the SPL Token Mint",
+    },
+    {
+      code: 6015,
+      name: "FreezeAuthorityNotProgram",
+      msg: "The wrapper authority is not the freeze authority of the Confidential Wrapped Token Mint",
+    },
+    {
+      code: 6016,
+      name: "Paused",
+      msg: "Wrapping and unwrapping are paused",
+    },
+    {
+      code: 6017,
+      name: "Unauthorized",
+      msg: "The signer is not allowed to perform this action",
+    },
+    {
+      code: 6018,
+      name: "NotUpgradeAuthority",
+      msg: "The signer is not the upgrade authority of the program",
+    },
+    {
+      code: 6019,
+      name: "FeeTooHigh",
+      msg: "The fee exceeds the maximum fee",
+    },
+    {
+      code: 6020,
+      name: "WrapAmountTooSmall",
+      msg: "The amount is below the minimum wrap amount",
+    },
+    {
+      code: 6021,
+      name: "WrapAmountTooLarge",
+      msg: "The amount is above the maximum wrap amount per transaction",
+    },
+    {
+      code: 6022,
+      name: "MaxSupplyExceeded",
+      msg: "Wrapping would exceed the maximum supply of the Confidential Wrapped Token",
+    },
+    {
+      code: 6023,
+      name: "InvalidLimits",
+      msg: "The minimum wrap amount exceeds the maximum wrap amount per transaction",
+    },
+    {
+      code: 6024,
+      name: "UnwrapLimitExceeded",
+      msg: "The amount exceeds the remaining unwrap capacity of the current window",
+    },
+    {
+      code: 6025,
+      name: "InvalidUnwrapWindow",
+      msg: "The unwrap window must be at least one slot",
+    },
+    {
+      code: 6026,
+      name: "SupplyNotZero",
+      msg: "The supply of the Confidential Wrapped Token is not zero",
+    },
+    {
+      code: 6027,
+      name: "VaultNotEmpty",
+      msg: "The vault or the fee vault still holds SPL Tokens",
+    },
+    {
+      code: 6028,
+      name: "TreasuryNotSet",
+      msg: "No treasury is set for the wrapper",
+    },
+    {
+      code: 6029,
+      name: "InvalidTreasury",
+      msg: "The token account is not the treasury of the wrapper",
+    },
+    {
+      code: 6030,
+      name: "NoSurplus",
+      msg: "The vault holds no surplus",
+    },
+    {
+      code: 6031,
+      name: "AuditorNotAuthorized",
+      msg: "Only the mint authority or an admin may choose the auditor",
+    },
+    {
+      code: 6032,
+      name: "AuthorityAlreadyMigrated",
+      msg: "The wrapper already has its own authority",
+    },
+    {
+      code: 6033,
+      name: "NotLegacyWrapper",
+      msg: "The Confidential Wrapped Token Mint was not created by a legacy wrapper",
+    },
+    {
+      code: 6034,
+      name: "AdminNotAuthorized",
+      msg: "Only the mint authority or the admin of the program may choose the wrapper admin",
+    },
+    {
+      code: 6035,
+      name: "ApprovalNotAuthorized",
+      msg: "Only the mint authority or the admin of the program may require approval",
+    },
+    {
+      code: 6036,
+      name: "FreezeAuthorityRevoked",
+      msg: "The freeze authority of the Confidential Wrapped Token Mint was revoked and cannot be restored",
+    },
+    {
+      code: 6037,
+      name: "AccountNotApproved",
+      msg: "The Confidential Token Account has not been approved yet",
+    },
+  ],
 };